@@ -5,16 +5,28 @@ pub fn main(input: syn::DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
     let doc = utils::try_get_doc("all_doc", &input.attrs)?;
 
     let typ = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let doc = doc
         .as_deref()
         .unwrap_or("Returns an array of all elements on this enum.");
-    let len = variants.len();
-    let idents = variants.into_iter().map(|p| p.ident);
+
+    let mut items = Vec::with_capacity(variants.len());
+    for var in variants {
+        let ident = var.ident;
+        items.push((
+            utils::Cfg::from_attrs(&var.attrs)?,
+            quote::quote! { Self::#ident },
+        ));
+    }
+    // variants disabled by #[cfg] don't count
+    let len = utils::quote_cfg_len(items.iter().map(|i| &i.0));
+    let all = utils::quote_cfg_array(items.iter().map(|(cfg, item)| (cfg, item.clone())));
+    let constness = utils::quote_const(&input.generics);
     Ok(quote::quote! {
-        impl #typ {
+        impl #impl_generics #typ #ty_generics #where_clause {
             #[doc = #doc]
-            pub const fn all() -> [Self; #len] {
-                [#(Self::#idents ,)*]
+            pub #constness fn all() -> [Self; #len] {
+                #all
             }
         }
     })
@@ -1,30 +1,120 @@
-use syn::{spanned::Spanned, Lit, MetaList, NestedMeta};
+use proc_macro2::Ident;
+use syn::{spanned::Spanned, Error, Lit, Meta, MetaList, MetaNameValue, NestedMeta};
 
+use super::super::Scope;
 use crate::utils::SpannedString;
 
 pub struct Attrs {
     pub aliases: Option<Aliases>,
     pub rename: Option<Rename>,
+    pub parse: Option<super::ParseMode>,
+    pub deprecated_aliases: Option<DeprecatedAliases>,
+    /// unknown strings parse into this variant
+    pub other: bool,
+    pub repr: Option<Reprs>,
+    pub locale: Option<Locales>,
+    pub skip: Option<Skip>,
 }
 
 impl Attrs {
     pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         let mut rename = None;
         let mut aliases = None;
+        let mut parse = None;
+        let mut deprecated_aliases = None;
+        let mut other = false;
+        let mut repr = None;
+        let mut locale = None;
+        let mut skip = None;
 
         for attr in attrs {
+            if let Some(all) = Skip::from_bare_attr(attr)? {
+                if skip.is_some() {
+                    return Err(Error::new_spanned(attr, "duplicate attribute!"));
+                }
+                skip = Some(all);
+                continue;
+            }
             try_get! {attr;
                 rename => Rename,
-                aliases => Aliases
+                aliases => Aliases,
+                parse => super::ParseMode,
+                deprecated_aliases => DeprecatedAliases,
+                repr => Reprs,
+                locale => Locales,
+                skip => Skip;
+                other
+            }
+        }
+        Ok(Self {
+            aliases,
+            rename,
+            parse,
+            deprecated_aliases,
+            other,
+            repr,
+            locale,
+            skip,
+        })
+    }
+}
+
+/// what this variant is left out of
+#[derive(Default)]
+pub struct Skip {
+    /// isn't accepted when parsing
+    pub parse: bool,
+    /// has no string of its own
+    pub display: bool,
+}
+
+impl Skip {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, "skip(parse? display?)");
+
+        let mut skip = Self::default();
+        for nested in attr.nested {
+            let ident = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.get_ident().cloned(),
+                _ => None,
+            }
+            .ok_or_else(malformed_err)?;
+
+            let field = match &*ident.to_string() {
+                "parse" => &mut skip.parse,
+                "display" => &mut skip.display,
+                _ => return Err(malformed_err()),
+            };
+            if *field {
+                return Err(Error::new_spanned(ident, "duplicate value!"));
             }
+            *field = true;
         }
-        Ok(Self { aliases, rename })
+
+        if !skip.parse && !skip.display {
+            return Err(malformed_err());
+        }
+        Ok(skip)
+    }
+    /// A bare `#[fromtostr(skip)]` skips everything, and try_get only parses lists
+    pub fn from_bare_attr(attr: &syn::Attribute) -> syn::Result<Option<Self>> {
+        if !attr.path.is_ident("fromtostr") {
+            return Ok(None);
+        }
+        Ok(match attr.parse_args()? {
+            Meta::Path(path) if path.is_ident("skip") => Some(Self {
+                parse: true,
+                display: true,
+            }),
+            _ => None,
+        })
     }
 }
 
 pub enum Rename {
     Renamed(SpannedString),
-    Format(super::FormatCase),
+    Format(super::Style),
 }
 
 impl Rename {
@@ -32,7 +122,7 @@ impl Rename {
         let attr_span = attr.span();
         let malformed_err = malformed_err!(attr_span, r#"rename("...") or rename(style = "...")"#);
 
-        if let Ok(format) = super::FormatCase::from_attr(&attr, malformed_err) {
+        if let Ok(format) = super::Style::from_attr(&attr, malformed_err) {
             return Ok(Self::Format(format));
         }
         let mut string = None;
@@ -51,12 +141,51 @@ impl Rename {
     }
 }
 
-pub struct Aliases(pub Vec<SpannedString>);
+pub struct Aliases(pub Vec<(SpannedString, Option<Scope>)>);
 
 impl Aliases {
     pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
         let attr_span = attr.span();
-        let malformed_err = malformed_err!(attr_span, r#"aliases("...",*)"#);
+        let malformed_err = malformed_err!(
+            attr_span,
+            r#"aliases("..."*, serde = "..."*, fromstr = "..."*)"#
+        );
+
+        let mut vec = Vec::with_capacity(attr.nested.len());
+        for nested in attr.nested {
+            match nested {
+                NestedMeta::Lit(Lit::Str(s)) => vec.push((s.into(), None)),
+                NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                    path,
+                    lit: Lit::Str(s),
+                    ..
+                })) => {
+                    let scope = if path.is_ident("serde") {
+                        Scope::Serde
+                    } else if path.is_ident("fromstr") {
+                        Scope::FromStr
+                    } else {
+                        return Err(malformed_err());
+                    };
+                    vec.push((s.into(), Some(scope)));
+                }
+                _ => return Err(malformed_err()),
+            }
+        }
+
+        if vec.is_empty() {
+            return Err(malformed_err());
+        }
+        Ok(Self(vec))
+    }
+}
+
+pub struct DeprecatedAliases(pub Vec<SpannedString>);
+
+impl DeprecatedAliases {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"deprecated_aliases("..."*)"#);
 
         let mut vec = Vec::with_capacity(attr.nested.len());
         for nested in attr.nested {
@@ -73,3 +202,56 @@ impl Aliases {
         Ok(Self(vec))
     }
 }
+
+/// value of each named representation for this variant
+pub struct Reprs(pub Vec<(Ident, SpannedString)>);
+
+impl Reprs {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"repr(name = "..."*)"#);
+
+        named_strings(attr, malformed_err).map(Self)
+    }
+}
+
+/// translation of this variant for each locale
+pub struct Locales(pub Vec<(Ident, SpannedString)>);
+
+impl Locales {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"locale(locale = "..."*)"#);
+
+        named_strings(attr, malformed_err).map(Self)
+    }
+}
+
+/// parses `name = "..."` pairs, with unique names
+fn named_strings(
+    attr: MetaList,
+    malformed_err: impl Fn() -> Error + Copy,
+) -> syn::Result<Vec<(Ident, SpannedString)>> {
+    let mut vec: Vec<(Ident, SpannedString)> = Vec::with_capacity(attr.nested.len());
+    for nested in attr.nested {
+        match nested {
+            NestedMeta::Meta(Meta::NameValue(MetaNameValue {
+                path,
+                lit: Lit::Str(s),
+                ..
+            })) => {
+                let name = path.get_ident().ok_or_else(malformed_err)?;
+                if vec.iter().any(|(n, _)| n == name) {
+                    return Err(Error::new_spanned(name, "duplicate name!"));
+                }
+                vec.push((name.clone(), s.into()));
+            }
+            _ => return Err(malformed_err()),
+        }
+    }
+
+    if vec.is_empty() {
+        return Err(malformed_err());
+    }
+    Ok(vec)
+}
@@ -1,11 +1,24 @@
+use proc_macro2::Span;
 use syn::{spanned::Spanned, Error, Lit, Meta, MetaList, NestedMeta};
 
+use super::words::{self, Digits};
+
 macro_rules! try_get {
-    ($attr:ident; $($field:ident => $typ:ty),*) => {
+    ($attr:ident; $($field:ident => $typ:ty),* $(; $($flag:ident),*)?) => {
         if !$attr.path.is_ident("fromtostr") {
             continue;
         };
-        let list: ::syn::MetaList = $attr.parse_args()?;
+        let list = match $attr.parse_args()? {
+            ::syn::Meta::List(list) => list,
+            $($(::syn::Meta::Path(path) if path.is_ident(stringify![$flag]) => {
+                if !$flag {
+                    $flag = true;
+                    continue;
+                };
+                return Err(::syn::Error::new_spanned(path, "duplicate attribute!"));
+            })*)?
+            meta => return Err(::syn::Error::new_spanned(meta, "unknown attribute!")),
+        };
 
         $(if list.path.is_ident(stringify![$field]) {
             if $field.is_none() {
@@ -32,6 +45,23 @@ macro_rules! malformed_err {
 pub mod inner;
 pub mod outer;
 
+/// Parses `#[fromtostr(crate = "...")]`, which try_get can't since it isn't a list.
+pub fn crate_path(attr: &syn::Attribute) -> syn::Result<Option<syn::Path>> {
+    if !attr.path.is_ident("fromtostr") {
+        return Ok(None);
+    }
+    match attr.parse_args()? {
+        Meta::NameValue(nv) if nv.path.is_ident("crate") => match nv.lit {
+            Lit::Str(s) => s.parse().map(Some),
+            lit => Err(Error::new_spanned(
+                lit,
+                r#"malformed attribute, expected #[fromtostr(crate = "...")]"#,
+            )),
+        },
+        _ => Ok(None),
+    }
+}
+
 pub enum FormatCase {
     /// keep it as is
     None,
@@ -63,11 +93,22 @@ pub enum FormatCase {
     CamelSnake,
 }
 
-impl FormatCase {
+/// A [`FormatCase`], how the words it formats are found, and what goes around them
+pub struct Style {
+    pub case: FormatCase,
+    pub digits: Digits,
+    pub prefix: String,
+    pub suffix: String,
+}
+
+impl Style {
     pub fn from_attr(attr: &MetaList, malformed_err: impl FnOnce() -> Error) -> syn::Result<Self> {
         let attr_span = attr.span();
         let mut separator = None;
         let mut style = None;
+        let mut digits = None;
+        let mut prefix = None;
+        let mut suffix = None;
 
         for nested in &attr.nested {
             let nv = match nested {
@@ -81,19 +122,49 @@ impl FormatCase {
             ) {
                 (Some("separator"), Lit::Str(sep)) => separator = Some(sep.value()),
                 (Some("style"), Lit::Str(sty)) => style = Some((sty.value(), sty.span())),
+                (Some("digits"), Lit::Str(dig)) => {
+                    digits = Some(Digits::from_str(&dig.value()).ok_or_else(|| {
+                        Error::new(dig.span(), r#"expected digits = "attach" or "separate""#)
+                    })?)
+                }
+                (Some("prefix"), Lit::Str(pre)) => prefix = Some(pre.value()),
+                (Some("suffix"), Lit::Str(suf)) => suffix = Some(suf.value()),
                 _ => return Err(malformed_err()),
             }
         }
 
-        let (style, not_style_error) = match style {
-            Some(a) => {
-                let e = a.1;
-                (a.0, move |s| {
-                    Error::new(e, format!("{} not an avaliable style!", s))
-                })
-            }
-            None => return Err(malformed_err()),
-        };
+        match style {
+            Some((style, style_span)) => Ok(Self {
+                case: FormatCase::from_style(&style, separator, style_span, attr_span)?,
+                digits: digits.unwrap_or_default(),
+                prefix: prefix.unwrap_or_default(),
+                suffix: suffix.unwrap_or_default(),
+            }),
+            None => Err(malformed_err()),
+        }
+    }
+
+    #[inline]
+    pub fn format(&self, s: &str) -> String {
+        self.format_as(&self.case, s)
+    }
+
+    /// Formats with `case` instead, keeping everything else
+    pub fn format_as(&self, case: &FormatCase, s: &str) -> String {
+        let mut string = self.prefix.clone();
+        string.push_str(&case.format(s, self.digits));
+        string.push_str(&self.suffix);
+        string
+    }
+}
+
+impl FormatCase {
+    pub fn from_style(
+        style: &str,
+        separator: Option<String>,
+        style_span: Span,
+        attr_span: Span,
+    ) -> syn::Result<Self> {
         let no_sep_error =
             || Error::new(attr_span, r#"style = "delimited" needs a separator value"#);
         Ok(match style.trim() {
@@ -129,55 +200,158 @@ impl FormatCase {
                     return Err(no_sep_error());
                 }
             }
-            s => return Err(not_style_error(s)),
+            s => {
+                return Err(Error::new(
+                    style_span,
+                    format!("{} not an avaliable style!", s),
+                ))
+            }
         })
     }
 
-    pub fn format(&self, s: &str) -> String {
+    pub fn format(&self, s: &str, digits: Digits) -> String {
+        if let Self::None = self {
+            return s.to_owned();
+        }
+        let words = words::split(s, digits);
+
         match self {
-            Self::None => s.to_owned(),
-            Self::Lower => s.to_lowercase(),
-            Self::Upper => s.to_uppercase(),
-            Self::Camel => s[..1].to_lowercase() + &s[1..],
-            Self::Delimited { sep } => Self::delimit(sep, s),
-            Self::DelimitedLower { sep } => Self::delimit(sep, s).to_lowercase(),
-            Self::DelimitedUpper { sep } => Self::delimit(sep, s).to_uppercase(),
-            Self::Train => Self::delimit("-", s),
-            Self::PascalSnake => Self::delimit("_", s),
-            Self::CamelSnake => {
-                let s = Self::delimit("_", s);
-
-                s[..1].to_lowercase() + &s[1..]
+            Self::None => unreachable!(),
+            Self::Lower => words.concat().to_lowercase(),
+            Self::Upper => words.concat().to_uppercase(),
+            Self::Camel => Self::camel("", &words),
+            Self::Delimited { sep } => Self::title(sep, &words),
+            Self::DelimitedLower { sep } => words.join(sep).to_lowercase(),
+            Self::DelimitedUpper { sep } => words.join(sep).to_uppercase(),
+            Self::Train => Self::title("-", &words),
+            Self::PascalSnake => Self::title("_", &words),
+            Self::CamelSnake => Self::camel("_", &words),
+            Self::Snake => words.join("_").to_lowercase(),
+            Self::Kebab => words.join("-").to_lowercase(),
+            Self::ScreamingSnake => words.join("_").to_uppercase(),
+            Self::ScreamingKebab => words.join("-").to_uppercase(),
+        }
+    }
+
+    /// lowercases the first word, capitalizes the others, then delimits every word with `sep`
+    fn camel(sep: &str, words: &[&str]) -> String {
+        let mut string = String::new();
+        for (i, word) in words.iter().enumerate() {
+            if i == 0 {
+                string.push_str(&word.to_lowercase());
+            } else {
+                string.push_str(sep);
+                Self::capitalize(&mut string, word);
+            }
+        }
+        string
+    }
+
+    /// capitalizes every word, then delimits them with `sep`
+    fn title(sep: &str, words: &[&str]) -> String {
+        let mut string = String::new();
+        for (i, word) in words.iter().enumerate() {
+            if i != 0 {
+                string.push_str(sep);
             }
-            Self::Snake => Self::delimit("_", s).to_lowercase(),
-            Self::Kebab => Self::delimit("-", s).to_lowercase(),
-            Self::ScreamingSnake => Self::delimit("_", s).to_uppercase(),
-            Self::ScreamingKebab => Self::delimit("-", s).to_uppercase(),
+            Self::capitalize(&mut string, word);
         }
+        string
     }
 
-    pub fn delimit(sep: &str, s: &str) -> String {
-        let mut string = String::with_capacity(s.len() + (4 * sep.len()));
-        let mut chars = s.chars().peekable();
-        // don't try to add an separator at the start
-        string.push(chars.next().unwrap());
+    /// pushes `word` with its first character uppercased, keeping initialisms as they are
+    fn capitalize(string: &mut String, word: &str) {
+        let mut chars = word.chars();
+        if let Some(first) = chars.next() {
+            string.extend(first.to_uppercase());
+            string.push_str(chars.as_str());
+        }
+    }
+}
+
+/// How strict parsing is, the default being exact matches only
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct ParseMode {
+    /// "TwoWords" == "twowords"
+    pub case_insensitive: bool,
+    /// "TwoWords" == "Two_Words" == "Two-Words" == "Two Words"
+    pub ignore_separators: bool,
+}
 
-        let mut prev_is_upper = None;
-        while let Some(ch) = chars.next() {
-            let is_upper = ch.is_uppercase();
-            let next_is_upper = chars.peek().map(|ch| ch.is_uppercase());
+impl ParseMode {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(
+            attr_span,
+            r#"parse(exact) or parse(case_insensitive?, ignore_separators?)"#
+        );
 
-            // if this character is upper, and neither the previous and next is upper, add separator
-            if is_upper && (next_is_upper == Some(false) || prev_is_upper == Some(false)) {
-                string.push_str(sep);
+        if attr.nested.is_empty() {
+            return Err(malformed_err());
+        }
+
+        let mut mode = Self::default();
+        let mut exact = false;
+        for nested in &attr.nested {
+            let ident = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => path.get_ident().ok_or_else(malformed_err)?,
+                _ => return Err(malformed_err()),
+            };
+
+            match &*ident.to_string() {
+                "exact" => exact = true,
+                "case_insensitive" => mode.case_insensitive = true,
+                "ignore_separators" => mode.ignore_separators = true,
+                _ => return Err(Error::new_spanned(ident, "not an avaliable parse mode!")),
             }
-            string.push(ch);
-            prev_is_upper = Some(is_upper);
         }
 
-        string.shrink_to_fit();
+        if exact && !mode.is_exact() {
+            return Err(Error::new(
+                attr_span,
+                "parse(exact) can't be combined with other parse modes",
+            ));
+        }
+        Ok(mode)
+    }
+
+    #[inline]
+    pub fn is_exact(self) -> bool {
+        self == Self::default()
+    }
+
+    #[inline]
+    pub fn union(self, other: Self) -> Self {
+        Self {
+            case_insensitive: self.case_insensitive || other.case_insensitive,
+            ignore_separators: self.ignore_separators || other.ignore_separators,
+        }
+    }
+
+    /// should match `fieldless_enum_tools::__internal::eq_normalized`
+    pub fn normalize(self, s: &str) -> String {
+        let mut string = String::with_capacity(s.len());
+        for ch in s.chars() {
+            if self.ignore_separators && matches!(ch, '_' | '-' | ' ') {
+                continue;
+            }
+            if self.case_insensitive {
+                string.extend(ch.to_lowercase());
+            } else {
+                string.push(ch);
+            }
+        }
         string
     }
+
+    pub fn describe(self) -> &'static str {
+        match (self.case_insensitive, self.ignore_separators) {
+            (false, false) => "exactly",
+            (true, false) => "case insensitively",
+            (false, true) => "ignoring separators",
+            (true, true) => "case insensitively and ignoring separators",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -189,18 +363,121 @@ mod tests {
         use FormatCase::*;
         let orig = "TwoWords";
 
-        assert_eq!(Lower.format(orig), "twowords");
-        assert_eq!(Upper.format(orig), "TWOWORDS");
-        assert_eq!(Camel.format(orig), "twoWords");
-        assert_eq!(Snake.format(orig), "two_words");
-        assert_eq!(ScreamingSnake.format(orig), "TWO_WORDS");
-        assert_eq!(Kebab.format(orig), "two-words");
-        assert_eq!(ScreamingKebab.format(orig), "TWO-WORDS");
-        assert_eq!(Train.format(orig), "Two-Words");
-        assert_eq!(PascalSnake.format(orig), "Two_Words");
-        assert_eq!(CamelSnake.format(orig), "two_Words");
+        assert_eq!(Lower.format(orig, Digits::Attach), "twowords");
+        assert_eq!(Upper.format(orig, Digits::Attach), "TWOWORDS");
+        assert_eq!(Camel.format(orig, Digits::Attach), "twoWords");
+        assert_eq!(Snake.format(orig, Digits::Attach), "two_words");
+        assert_eq!(ScreamingSnake.format(orig, Digits::Attach), "TWO_WORDS");
+        assert_eq!(Kebab.format(orig, Digits::Attach), "two-words");
+        assert_eq!(ScreamingKebab.format(orig, Digits::Attach), "TWO-WORDS");
+        assert_eq!(Train.format(orig, Digits::Attach), "Two-Words");
+        assert_eq!(PascalSnake.format(orig, Digits::Attach), "Two_Words");
+        assert_eq!(CamelSnake.format(orig, Digits::Attach), "two_Words");
 
         // check if initialisms work
-        assert_eq!(Train.format("HTTPRequest"), "HTTP-Request");
+        assert_eq!(Train.format("HTTPRequest", Digits::Attach), "HTTP-Request");
+
+        let table: &[(FormatCase, &str, Digits, &str)] = &[
+            (None, "Two_Words", Digits::Attach, "Two_Words"),
+            (Lower, "Two_Words", Digits::Attach, "twowords"),
+            (Upper, "Http2Error", Digits::Attach, "HTTP2ERROR"),
+            (Camel, "HTTPRequest", Digits::Attach, "httpRequest"),
+            (Camel, "Żółw", Digits::Attach, "żółw"),
+            (Camel, "snake_case", Digits::Attach, "snakeCase"),
+            (Camel, "Two_Words", Digits::Attach, "twoWords"),
+            (Camel, "MyHTTPRequest", Digits::Attach, "myHTTPRequest"),
+            (Snake, "HTTPRequest", Digits::Attach, "http_request"),
+            (Snake, "Http2Error", Digits::Attach, "http2_error"),
+            (Snake, "Http2Error", Digits::Separate, "http_2_error"),
+            (Snake, "HTTP2Error", Digits::Attach, "http2_error"),
+            (Snake, "Ipv4Addr", Digits::Attach, "ipv4_addr"),
+            (Snake, "Utf8", Digits::Attach, "utf8"),
+            (Snake, "Utf8", Digits::Separate, "utf_8"),
+            (Snake, "Two_Words", Digits::Attach, "two_words"),
+            (
+                Kebab,
+                "Status404NotFound",
+                Digits::Attach,
+                "status404-not-found",
+            ),
+            (
+                Kebab,
+                "Status404NotFound",
+                Digits::Separate,
+                "status-404-not-found",
+            ),
+            (ScreamingSnake, "Ipv4Addr", Digits::Separate, "IPV_4_ADDR"),
+            (
+                ScreamingKebab,
+                "MyHTTPRequest",
+                Digits::Attach,
+                "MY-HTTP-REQUEST",
+            ),
+            (Train, "Ipv4Addr", Digits::Attach, "Ipv4-Addr"),
+            (Train, "snake_case", Digits::Attach, "Snake-Case"),
+            (PascalSnake, "Two_Words", Digits::Attach, "Two_Words"),
+            (PascalSnake, "snake_case", Digits::Attach, "Snake_Case"),
+            (CamelSnake, "HTTPRequest", Digits::Attach, "http_Request"),
+            (CamelSnake, "snake_case", Digits::Attach, "snake_Case"),
+            (
+                Delimited {
+                    sep: ".".to_owned(),
+                },
+                "Http2Error",
+                Digits::Separate,
+                "Http.2.Error",
+            ),
+            (
+                Delimited {
+                    sep: " ".to_owned(),
+                },
+                "snake_case",
+                Digits::Attach,
+                "Snake Case",
+            ),
+            (
+                DelimitedLower {
+                    sep: " ".to_owned(),
+                },
+                "HTTPRequest",
+                Digits::Attach,
+                "http request",
+            ),
+            (
+                DelimitedUpper {
+                    sep: "::".to_owned(),
+                },
+                "Utf8Str",
+                Digits::Attach,
+                "UTF8::STR",
+            ),
+        ];
+        for (case, orig, digits, formatted) in table {
+            assert_eq!(
+                case.format(orig, *digits),
+                *formatted,
+                "{} with {:?}",
+                orig,
+                digits
+            );
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        let ci = ParseMode {
+            case_insensitive: true,
+            ignore_separators: false,
+        };
+        let is = ParseMode {
+            case_insensitive: false,
+            ignore_separators: true,
+        };
+        let orig = "Two_Words-Żółw";
+
+        assert_eq!(ParseMode::default().normalize(orig), orig);
+        assert_eq!(ci.normalize(orig), "two_words-żółw");
+        assert_eq!(is.normalize(orig), "TwoWordsŻółw");
+        assert_eq!(ci.union(is).normalize(orig), "twowordsżółw");
     }
 }
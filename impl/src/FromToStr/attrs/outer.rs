@@ -1,28 +1,147 @@
 pub use super::super::Impl;
-use syn::{spanned::Spanned, Error, Meta, MetaList, NestedMeta};
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::{spanned::Spanned, Error, Lit, Meta, MetaList, NestedMeta, Visibility};
+
+use crate::utils::SpannedString;
 
 pub struct Attrs {
     pub format: Option<Format>,
     pub skip: Option<Skip>,
+    pub error: Option<ErrorType>,
+    pub parse: Option<super::ParseMode>,
+    pub accept_styles: Option<AcceptStyles>,
+    pub as_str: Option<ConstFn>,
+    pub from_str_const: Option<ConstFn>,
+    pub matcher: Option<Matcher>,
+    pub serde: Option<Serde>,
+    pub suggest: bool,
+    /// implement clap's `ValueEnum`, which needs `Clone`
+    pub value_enum: bool,
+    /// implement sqlx's `Type`, `Encode` and `Decode` for sqlite
+    pub sqlx: bool,
+    pub repr: Option<Reprs>,
+    /// path to this crate in generated code
+    pub krate: Option<syn::Path>,
+    /// string of variants with `skip(display)`
+    pub placeholder: Option<Placeholder>,
+    /// doc comment of the enum
+    pub doc: Option<String>,
 }
 
 impl Attrs {
     pub fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
         let mut format = None;
         let mut skip = None;
+        let mut error = None;
+        let mut parse = None;
+        let mut accept_styles = None;
+        let mut as_str = None;
+        let mut from_str_const = None;
+        let mut matcher = None;
+        let mut serde = None;
+        let mut suggest = false;
+        let mut value_enum = false;
+        let mut sqlx = false;
+        let mut repr = None;
+        let mut krate = None;
+        let mut placeholder = None;
 
         for attr in attrs {
+            if let Some(path) = super::crate_path(attr)? {
+                if krate.is_some() {
+                    return Err(Error::new_spanned(attr, "duplicate attribute!"));
+                }
+                krate = Some(path);
+                continue;
+            }
             try_get! {attr;
                 format => Format,
-                skip => Skip
+                skip => Skip,
+                error => ErrorType,
+                parse => super::ParseMode,
+                accept_styles => AcceptStyles,
+                as_str => ConstFn,
+                from_str_const => ConstFn,
+                matcher => Matcher,
+                serde => Serde,
+                repr => Reprs,
+                placeholder => Placeholder;
+                suggest,
+                value_enum,
+                sqlx
+            }
+        }
+        let this = Self {
+            format,
+            skip,
+            error,
+            parse,
+            accept_styles,
+            as_str,
+            from_str_const,
+            matcher,
+            serde,
+            suggest,
+            value_enum,
+            sqlx,
+            repr,
+            krate,
+            placeholder,
+            doc: crate::utils::get_doc_comment(attrs),
+        };
+        // skips can come after `repr`, so names are checked once everything is parsed
+        if let Some(ref repr) = this.repr {
+            for (name, _) in &repr.0 {
+                this.check_repr_name(name)?;
             }
         }
-        Ok(Self { format, skip })
+        Ok(this)
     }
     #[inline]
     pub fn should_skip(&self, imp: Impl) -> bool {
         self.skip.as_ref().map_or(false, |s| s.should_skip(imp))
     }
+    /// errors if `as_{name}` or `from_{name}` of a representation is a function generated anyway
+    pub fn check_repr_name(&self, name: &Ident) -> syn::Result<()> {
+        let mut generated = Vec::new();
+        if !self.should_skip(Impl::AsStr) {
+            generated.push(ConstFn::vis_and_name(&self.as_str, "as_str").1.to_string());
+        }
+        if !self.should_skip(Impl::FromStrConst) {
+            generated.push(
+                ConstFn::vis_and_name(&self.from_str_const, "from_str_const")
+                    .1
+                    .to_string(),
+            );
+        }
+        if !self.should_skip(Impl::FromBytes) {
+            generated.push("from_bytes".to_owned());
+        }
+
+        for function in [format!("as_{}", name), format!("from_{}", name)] {
+            if generated.contains(&function) {
+                return Err(Error::new_spanned(
+                    name,
+                    format!(
+                        "representation conflicts with the generated `{}`!",
+                        function
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+    /// `::fieldless_enum_tools`, unless it was changed with `crate = "..."`
+    pub fn crate_path(&self) -> TokenStream {
+        match self.krate {
+            Some(ref path) => quote::quote! { #path },
+            None => quote::quote! { ::fieldless_enum_tools },
+        }
+    }
+    #[inline]
+    pub fn matcher(&self) -> Matcher {
+        self.matcher.unwrap_or(Matcher::Match)
+    }
 }
 
 pub struct Skip(pub Vec<Impl>);
@@ -45,6 +164,21 @@ impl Skip {
                     "Display" => Impl::Display,
                     "Serialize" => Impl::Serialize,
                     "Deserialize" => Impl::Deserialize,
+                    "AsStr" => Impl::AsStr,
+                    "FromStrConst" => Impl::FromStrConst,
+                    "FromBytes" => Impl::FromBytes,
+                    "TryFromBytes" => Impl::TryFromBytes,
+                    "TryFromOsStr" => Impl::TryFromOsStr,
+                    "ParsePrefix" => Impl::ParsePrefix,
+                    "ParseWithInfo" => Impl::ParseWithInfo,
+                    "Names" => Impl::Names,
+                    "ValueEnum" => Impl::ValueEnum,
+                    "JsonSchema" => Impl::JsonSchema,
+                    "ToSql" => Impl::ToSql,
+                    "FromSql" => Impl::FromSql,
+                    "Type" => Impl::Type,
+                    "Encode" => Impl::Encode,
+                    "Decode" => Impl::Decode,
                     _ => return Err(Error::new_spanned(ident, "not an avaliable skip!")),
                 });
             } else {
@@ -59,17 +193,253 @@ impl Skip {
     }
 }
 
-pub struct Format(pub super::FormatCase);
+/// named representations, and how to format variants that don't set them
+pub struct Reprs(pub Vec<(Ident, Option<super::Style>)>);
+
+impl Reprs {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"repr((name | name(style = "..."))*)"#);
+
+        let mut vec: Vec<(Ident, Option<super::Style>)> = Vec::with_capacity(attr.nested.len());
+        for nested in attr.nested {
+            let (path, format) = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => (path, None),
+                NestedMeta::Meta(Meta::List(list)) => {
+                    let format = super::Style::from_attr(&list, malformed_err)?;
+                    (list.path, Some(format))
+                }
+                _ => return Err(malformed_err()),
+            };
+            let name = path.get_ident().ok_or_else(malformed_err)?;
+            if vec.iter().any(|(n, _)| n == name) {
+                return Err(Error::new_spanned(name, "duplicate representation!"));
+            }
+            vec.push((name.clone(), format));
+        }
+
+        if vec.is_empty() {
+            return Err(malformed_err());
+        }
+        Ok(Self(vec))
+    }
+}
+
+pub struct Placeholder(pub SpannedString);
+
+impl Placeholder {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"placeholder("...")"#);
+
+        let mut nested = attr.nested.into_iter();
+        match (nested.next(), nested.next()) {
+            (Some(NestedMeta::Lit(Lit::Str(s))), None) => Ok(Self(s.into())),
+            _ => Err(malformed_err()),
+        }
+    }
+}
+
+pub struct Format(pub super::Style);
 
 impl Format {
     pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
         let attr_span = attr.span();
         let malformed_err = malformed_err!(attr_span, r#"format(style = "...")"#);
 
-        Ok(Self(super::FormatCase::from_attr(&attr, malformed_err)?))
+        Ok(Self(super::Style::from_attr(&attr, malformed_err)?))
     }
-    #[inline]
-    pub fn format(&self, s: &str) -> String {
-        self.0.format(s)
+}
+
+pub struct AcceptStyles(pub Vec<super::FormatCase>);
+
+impl AcceptStyles {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"accept_styles("..."*)"#);
+
+        let mut vec = Vec::with_capacity(attr.nested.len());
+        for nested in attr.nested {
+            if let NestedMeta::Lit(Lit::Str(s)) = nested {
+                vec.push(super::FormatCase::from_style(
+                    &s.value(),
+                    None,
+                    s.span(),
+                    s.span(),
+                )?);
+            } else {
+                return Err(malformed_err());
+            }
+        }
+
+        if vec.is_empty() {
+            return Err(malformed_err());
+        }
+        Ok(Self(vec))
+    }
+}
+
+/// How `FromStr` finds the matching string
+#[derive(Clone, Copy)]
+pub enum Matcher {
+    /// a single `match` on the string
+    Match,
+    /// dispatches on the length first, then on the most distinguishing byte
+    Length,
+}
+
+impl Matcher {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"matcher("...")"#);
+
+        let mut nested = attr.nested.into_iter();
+        let matcher = match nested.next() {
+            Some(NestedMeta::Lit(Lit::Str(s))) => match &*s.value() {
+                "match" => Self::Match,
+                "length" => Self::Length,
+                m => {
+                    return Err(Error::new(
+                        s.span(),
+                        format!("{} not an avaliable matcher!", m),
+                    ))
+                }
+            },
+            _ => return Err(malformed_err()),
+        };
+
+        // if there's more than one thing inside the attr, error
+        if nested.next().is_some() {
+            return Err(malformed_err());
+        }
+
+        Ok(matcher)
+    }
+}
+
+/// What `Serialize` and `Deserialize` use instead of the string
+pub enum SerdeRepr {
+    /// the discriminant, casted to this integer type
+    Int(Ident),
+    /// the position of the variant on the enum, as an `u32`
+    Index,
+}
+
+pub struct Serde {
+    pub repr: SerdeRepr,
+    /// accept both the string and the repr when deserializing
+    pub lenient: bool,
+}
+
+impl Serde {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"serde(repr = "...", lenient?)"#);
+
+        let mut repr = None;
+        let mut lenient = false;
+        for nested in attr.nested {
+            match nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("lenient") => lenient = true,
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("repr") => {
+                    let s = match nv.lit {
+                        Lit::Str(s) => s,
+                        _ => return Err(malformed_err()),
+                    };
+                    repr = Some(match &*s.value() {
+                        "index" => SerdeRepr::Index,
+                        ty @ ("u8" | "u16" | "u32" | "u64" | "i8" | "i16" | "i32" | "i64") => {
+                            SerdeRepr::Int(Ident::new(ty, s.span()))
+                        }
+                        r => {
+                            return Err(Error::new(
+                                s.span(),
+                                format!("{} not an avaliable repr!", r),
+                            ))
+                        }
+                    });
+                }
+                _ => return Err(malformed_err()),
+            }
+        }
+
+        Ok(Self {
+            repr: repr.ok_or_else(malformed_err)?,
+            lenient,
+        })
+    }
+}
+
+pub struct ConstFn {
+    pub vis: Option<Visibility>,
+    pub name: Option<Ident>,
+}
+
+impl ConstFn {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let attr_name = attr.path.get_ident().map(ToString::to_string);
+        let malformed_err = move || {
+            Error::new(
+                attr_span,
+                format!(
+                    r#"malformed attribute, expected #[fromtostr({}(vis = "..."?, name = "..."?))]"#,
+                    attr_name.unwrap_or_default()
+                ),
+            )
+        };
+
+        let mut vis = None;
+        let mut name = None;
+        for nested in attr.nested {
+            let nv = match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) => nv,
+                _ => return Err(malformed_err()),
+            };
+
+            match (
+                nv.path.get_ident().map(ToString::to_string).as_deref(),
+                nv.lit,
+            ) {
+                (Some("vis"), Lit::Str(s)) => vis = Some(s.parse()?),
+                (Some("name"), Lit::Str(s)) => name = Some(s.parse()?),
+                _ => return Err(malformed_err()),
+            }
+        }
+
+        Ok(Self { vis, name })
+    }
+
+    /// visibility and name of the function, defaulting to `pub` and `default_name`
+    pub fn vis_and_name(this: &Option<Self>, default_name: &str) -> (Visibility, Ident) {
+        let this = this.as_ref();
+        (
+            this.and_then(|c| c.vis.clone())
+                .unwrap_or_else(|| syn::parse_quote!(pub)),
+            this.and_then(|c| c.name.clone())
+                .unwrap_or_else(|| Ident::new(default_name, Span::call_site())),
+        )
+    }
+}
+
+pub struct ErrorType(pub syn::Type);
+
+impl ErrorType {
+    pub fn from_attr(attr: MetaList) -> syn::Result<Self> {
+        let attr_span = attr.span();
+        let malformed_err = malformed_err!(attr_span, r#"error("...")"#);
+
+        let mut nested = attr.nested.into_iter();
+        let typ = match nested.next() {
+            Some(NestedMeta::Lit(Lit::Str(s))) => s.parse()?,
+            _ => return Err(malformed_err()),
+        };
+
+        // if there's more than one thing inside the attr, error
+        if nested.next().is_some() {
+            return Err(malformed_err());
+        }
+
+        Ok(Self(typ))
     }
 }
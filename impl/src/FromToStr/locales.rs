@@ -0,0 +1,92 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
+use super::{
+    attrs::outer::Attrs as OuterAttrs, is_displayable, quote_error, quote_from_str,
+    reprs::NamedRepr, Scope,
+};
+use crate::utils::Typ;
+
+/// Quotes `display_in` and `from_str_in`, choosing the strings by locale name.
+///
+/// Unknown locales use the main strings, same as `as_ref` and `FromStr`,
+/// and unknown strings are the `other` variant in every locale.
+pub fn quote_locales(
+    typ: &Typ,
+    locales: &[NamedRepr],
+    other: Option<&Ident>,
+    outer_attr: &OuterAttrs,
+) -> TokenStream {
+    let name = typ.ident.to_string();
+    let (impl_generics, _, where_clause) = typ.generics.split_for_impl();
+    let krate = outer_attr.crate_path();
+    let (error, map_err) = quote_error(outer_attr);
+
+    let mut display = TokenStream::new();
+    let mut from_str = TokenStream::new();
+    for locale in locales {
+        let locale_name = locale.name.to_string();
+        let idents = locale.variants.iter().map(|f| &f.original);
+        let cfgs = locale.variants.iter().map(|f| &f.cfg);
+        let strings = locale.variants.iter().map(|f| f.display_str(outer_attr));
+        let (matcher, _) = quote_from_str(
+            &locale.variants,
+            Scope::FromStr,
+            outer_attr,
+            quote! { ::core::option::Option::Some },
+            false,
+        );
+
+        display.extend(quote! {
+            #locale_name => match self {
+                #(#cfgs Self::#idents => #strings,)*
+            },
+        });
+        from_str.extend(quote! {
+            #locale_name => #matcher,
+        });
+    }
+    let locale_names = locales.iter().map(|l| l.name.to_string());
+    let fallback = match other {
+        Some(other) => quote! { Ok(Self::#other) },
+        None => quote! { Err(#krate::ParseEnumError::__new(#name, s)) #map_err },
+    };
+
+    let mut display_in = TokenStream::new();
+    if locales
+        .iter()
+        .all(|l| is_displayable(&l.variants, outer_attr))
+    {
+        display_in = quote! {
+            /// Returns the string of this variant in `locale`,
+            /// or the same as [`as_ref`](AsRef::as_ref) if `locale` is unknown.
+            pub fn display_in(&self, locale: &str) -> &'static str {
+                match locale {
+                    #display
+                    _ => self.__as_str(),
+                }
+            }
+        };
+    }
+
+    quote! {
+        impl #impl_generics #typ #where_clause {
+            /// Every locale with translations.
+            pub const LOCALES: &'static [&'static str] = &[#(#locale_names),*];
+
+            #display_in
+            /// Parses an variant from its exact string in `locale`,
+            /// or the same as [`FromStr`](::core::str::FromStr) if `locale` is unknown.
+            pub fn from_str_in(locale: &str, s: &str) -> ::core::result::Result<Self, #error> {
+                let parsed = match locale {
+                    #from_str
+                    _ => return Self::__from_str(s) #map_err,
+                };
+                match parsed {
+                    ::core::option::Option::Some(v) => Ok(v),
+                    ::core::option::Option::None => #fallback,
+                }
+            }
+        }
+    }
+}
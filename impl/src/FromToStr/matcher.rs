@@ -0,0 +1,88 @@
+use std::collections::BTreeMap;
+
+use proc_macro2::{Literal, TokenStream};
+use quote::quote;
+
+use super::attrs::outer::Matcher;
+use crate::utils::{Cfg, SpannedString};
+
+/// buckets with at most this many strings are matched directly
+const MAX_BUCKET: usize = 4;
+
+/// Quotes an expression matching `s` exactly against every string,
+/// evaluating to `Option` of the value next to the matching string.
+pub fn quote_matcher(
+    matcher: Matcher,
+    arms: &[(&SpannedString, TokenStream, &Cfg)],
+) -> TokenStream {
+    match matcher {
+        Matcher::Match => quote_match(arms),
+        Matcher::Length => {
+            let mut by_len: BTreeMap<usize, Vec<(&SpannedString, TokenStream, &Cfg)>> =
+                BTreeMap::new();
+            for arm in arms {
+                by_len
+                    .entry(arm.0.string.len())
+                    .or_default()
+                    .push(arm.clone());
+            }
+
+            let lens = by_len.keys();
+            let buckets = by_len.values().map(|b| quote_bucket(b));
+            quote! {
+                match s.len() {
+                    #(#lens => #buckets,)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    }
+}
+
+fn quote_match(arms: &[(&SpannedString, TokenStream, &Cfg)]) -> TokenStream {
+    let strings = arms.iter().map(|a| a.0);
+    let values = arms.iter().map(|a| &a.1);
+    let cfgs = arms.iter().map(|a| a.2);
+    quote! {
+        match s {
+            #(#cfgs #strings => ::core::option::Option::Some(#values),)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
+
+/// every string on the bucket has the same length,
+/// so dispatch on the byte that splits them the most
+fn quote_bucket(bucket: &[(&SpannedString, TokenStream, &Cfg)]) -> TokenStream {
+    if bucket.len() <= MAX_BUCKET {
+        return quote_match(bucket);
+    }
+
+    let len = bucket[0].0.string.len();
+    let pos = (0..len)
+        .max_by_key(|&i| {
+            let mut bytes: Vec<u8> = bucket.iter().map(|a| a.0.string.as_bytes()[i]).collect();
+            bytes.sort_unstable();
+            bytes.dedup();
+            // prefer the first position on ties
+            (bytes.len(), std::cmp::Reverse(i))
+        })
+        .unwrap();
+
+    let mut by_byte: BTreeMap<u8, Vec<(&SpannedString, TokenStream, &Cfg)>> = BTreeMap::new();
+    for arm in bucket {
+        by_byte
+            .entry(arm.0.string.as_bytes()[pos])
+            .or_default()
+            .push(arm.clone());
+    }
+
+    let bytes = by_byte.keys().map(|b| Literal::u8_suffixed(*b));
+    let matches = by_byte.values().map(|b| quote_match(b));
+    quote! {
+        match s.as_bytes()[#pos] {
+            #(#bytes => #matches,)*
+            _ => ::core::option::Option::None,
+        }
+    }
+}
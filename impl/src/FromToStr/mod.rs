@@ -1,42 +1,107 @@
 pub mod attrs;
+mod locales;
+mod matcher;
+mod reprs;
+mod words;
 use std::collections::HashMap;
 
-use attrs::{inner::Attrs as InnerAttrs, outer::Attrs as OuterAttrs};
-use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use attrs::{inner::Attrs as InnerAttrs, outer::Attrs as OuterAttrs, ParseMode};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::{quote, ToTokens};
 use syn::Error;
 
-use crate::utils::SpannedString;
+use crate::utils::{quote_cfg_array, quote_const, Cfg, SpannedString, Typ};
 
 pub fn main(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     let data = crate::utils::check_if_fieldless_enum("FromToStr", input.data)?;
     let outer_attr = OuterAttrs::from_attrs(&input.attrs)?;
 
     let mut fmtd = Vec::with_capacity(data.variants.len());
+    let mut variant_reprs = Vec::with_capacity(data.variants.len());
+    let mut variant_locales = Vec::with_capacity(data.variants.len());
     for var in data.variants {
-        let inner_attr = InnerAttrs::from_attrs(&var.attrs)?;
+        let mut inner_attr = InnerAttrs::from_attrs(&var.attrs)?;
+        let cfg = Cfg::from_attrs(&var.attrs)?;
+        let doc = crate::utils::get_doc_comment(&var.attrs);
 
-        fmtd.push(FormattedVariant::new(var.ident, inner_attr, &outer_attr));
+        let reprs = inner_attr.repr.take().map(|r| r.0).unwrap_or_default();
+        for (name, _) in &reprs {
+            outer_attr.check_repr_name(name)?;
+        }
+        variant_reprs.push(reprs);
+        variant_locales.push(inner_attr.locale.take().map(|l| l.0).unwrap_or_default());
+        let mut variant = FormattedVariant::new(var.ident, cfg, inner_attr, &outer_attr);
+        variant.doc = doc;
+        fmtd.push(variant);
     }
     check_if_duplicate(&fmtd)?;
+    check_if_multiple_other(&fmtd)?;
+
+    let declared = outer_attr.repr.as_ref().map_or(&[][..], |r| &r.0);
+    let reprs = reprs::NamedRepr::collect(&fmtd, &variant_reprs, declared);
+    // every locale falls back to the main strings
+    let locales = reprs::NamedRepr::collect(&fmtd, &variant_locales, &[]);
+    for repr in reprs.iter().chain(&locales) {
+        check_if_duplicate(&repr.variants)?;
+    }
 
-    let typ = input.ident;
-    let mut tree = Impl::default_impl(&typ, fmtd.as_slice());
+    let typ = Typ::new(input.ident, input.generics);
+    let mut tree = Impl::default_impl(&typ, fmtd.as_slice(), &outer_attr);
 
-    for imp in &Impl::default() {
-        let imp = *imp;
-        if !outer_attr.should_skip(imp) {
-            tree.extend(imp.quote_impl(&typ));
+    let displayable = is_displayable(&fmtd, &outer_attr);
+    // opt in, since it needs `Clone`
+    let value_enum = Some(Impl::ValueEnum).filter(|_| outer_attr.value_enum);
+    let sqlx = Impl::SQLX.iter().copied().filter(|_| outer_attr.sqlx);
+    for imp in Impl::default()
+        .iter()
+        .copied()
+        .chain(value_enum)
+        .chain(sqlx)
+    {
+        if !outer_attr.should_skip(imp) && (displayable || !imp.needs_display(&outer_attr)) {
+            tree.extend(imp.quote_impl(&typ, fmtd.as_slice(), &outer_attr));
         }
     }
+    for repr in &reprs {
+        tree.extend(repr.quote(&typ, &outer_attr));
+    }
+    if !locales.is_empty() {
+        let other = fmtd.iter().find(|f| f.other).map(|f| &f.original);
+        tree.extend(locales::quote_locales(&typ, &locales, other, &outer_attr));
+    }
 
     Ok(tree)
 }
 
 pub fn check_if_duplicate(fmtd: &[FormattedVariant]) -> syn::Result<()> {
+    check_scope_if_duplicate(fmtd, Scope::FromStr)?;
+    if fmtd.iter().any(FormattedVariant::has_scoped_aliases) {
+        check_scope_if_duplicate(fmtd, Scope::Serde)?;
+    }
+    Ok(())
+}
+
+/// if every variant has a string to display, otherwise nothing displaying them is generated
+pub fn is_displayable(fmtd: &[FormattedVariant], outer_attr: &OuterAttrs) -> bool {
+    fmtd.iter().all(|f| f.display_str(outer_attr).is_some())
+}
+
+fn check_if_multiple_other(fmtd: &[FormattedVariant]) -> syn::Result<()> {
+    let mut others = fmtd.iter().filter(|f| f.other).map(|f| &f.original);
+
+    if let (Some(first), Some(second)) = (others.next(), others.next()) {
+        let err_msg = "only one variant can be #[fromtostr(other)]!";
+        let mut error = Error::new(second.span(), err_msg);
+        error.combine(Error::new(first.span(), err_msg));
+        return Err(error);
+    }
+    Ok(())
+}
+
+fn check_scope_if_duplicate(fmtd: &[FormattedVariant], scope: Scope) -> syn::Result<()> {
     let mut hashes = HashMap::with_capacity(fmtd.len());
 
-    for fmt in fmtd.iter().flat_map(FormattedVariant::iter) {
+    for fmt in fmtd.iter().flat_map(|f| f.iter_scope(scope)) {
         let str = &fmt.string;
         let span1 = &fmt.span;
         // if there was already this string on the hashmap, error
@@ -49,39 +114,201 @@ pub fn check_if_duplicate(fmtd: &[FormattedVariant]) -> syn::Result<()> {
             });
         }
     }
+
+    let mut used: Vec<ParseMode> = Vec::new();
+    for f in fmtd {
+        if !used.contains(&f.mode) {
+            used.push(f.mode);
+        }
+    }
+
+    // a string can match two variants if they're equal when normalized with both modes
+    let mut modes: Vec<ParseMode> = Vec::new();
+    for a in &used {
+        for b in &used {
+            let mode = a.union(*b);
+            if !mode.is_exact() && !modes.contains(&mode) {
+                modes.push(mode);
+            }
+        }
+    }
+
+    for mode in modes {
+        let mut hashes: HashMap<String, Vec<(usize, ParseMode, &SpannedString)>> = HashMap::new();
+
+        for (i, f) in fmtd.iter().enumerate() {
+            for fmt in f.iter_scope(scope) {
+                let entry = hashes.entry(mode.normalize(&fmt.string)).or_default();
+
+                // two variants only clash on the mode they're both compared with
+                if let Some(&(_, _, other)) = entry
+                    .iter()
+                    .find(|(j, m, _)| *j != i && m.union(f.mode) == mode)
+                {
+                    let err_msg = format!(
+                        "ambiguous value! [{}] and [{}] are the same when parsed {}",
+                        other.string,
+                        fmt.string,
+                        mode.describe()
+                    );
+                    return Err({
+                        let mut error = Error::new(fmt.span, &err_msg);
+                        error.combine(Error::new(other.span, &err_msg));
+                        error
+                    });
+                }
+                entry.push((i, f.mode, fmt));
+            }
+        }
+    }
     Ok(())
 }
 
+/// Where a string is accepted
+#[derive(PartialEq, Eq, Clone, Copy)]
+pub enum Scope {
+    /// `FromStr` and everything else parsing strings
+    FromStr,
+    /// serde's `Deserialize`
+    Serde,
+}
+
 pub struct FormattedVariant {
     original: Ident,
+    /// `#[cfg(...)]` to put on everything generated for this variant
+    cfg: Cfg,
     formatted: SpannedString,
-    aliases: Vec<SpannedString>,
+    /// aliases, and where they're accepted if not everywhere
+    aliases: Vec<(SpannedString, Option<Scope>)>,
+    /// variant name in every style from `accept_styles`,
+    /// limited to one scope if the other already has the string as an alias
+    styled: Vec<(SpannedString, Option<Scope>)>,
+    deprecated: Vec<SpannedString>,
+    mode: ParseMode,
+    other: bool,
+    /// none of its strings are accepted
+    skip_parse: bool,
+    /// it only has the placeholder as a string
+    skip_display: bool,
+    /// its doc comment, used as help text
+    doc: Option<String>,
 }
 
 impl FormattedVariant {
-    pub fn new(ident: Ident, inner_attr: InnerAttrs, outer_attr: &OuterAttrs) -> Self {
-        Self {
-            formatted: {
-                let span = ident.span();
-                let ident = ident.to_string();
-                if let Some(ren) = inner_attr.rename {
-                    use attrs::inner::Rename;
-                    match ren {
-                        Rename::Renamed(ren) => ren,
-                        Rename::Format(f) => SpannedString::new(f.format(&ident), span),
-                    }
-                } else if let Some(ref f) = outer_attr.format {
-                    SpannedString::new(f.format(&ident), span)
-                } else {
-                    SpannedString::new(ident, span)
-                }
+    pub fn new(ident: Ident, cfg: Cfg, inner_attr: InnerAttrs, outer_attr: &OuterAttrs) -> Self {
+        use attrs::inner::Rename;
+
+        let span = ident.span();
+        let ident_str = ident.to_string();
+        // explicitly renamed variants don't get any styles
+        let renamed = matches!(inner_attr.rename, Some(Rename::Renamed(_)));
+        // the variant's own style replaces the enum's one
+        let format = match inner_attr.rename {
+            Some(Rename::Format(ref f)) => Some(f),
+            _ => outer_attr.format.as_ref().map(|f| &f.0),
+        };
+
+        let mut this = Self {
+            formatted: match (&inner_attr.rename, format) {
+                (Some(Rename::Renamed(ren)), _) => ren.clone(),
+                (_, Some(f)) => SpannedString::new(f.format(&ident_str), span),
+                (_, None) => SpannedString::new(ident_str.clone(), span),
             },
             original: ident,
+            cfg,
             aliases: inner_attr.aliases.map(|a| a.0).unwrap_or_default(),
+            styled: Vec::new(),
+            deprecated: inner_attr
+                .deprecated_aliases
+                .map(|a| a.0)
+                .unwrap_or_default(),
+            mode: inner_attr.parse.or(outer_attr.parse).unwrap_or_default(),
+            other: inner_attr.other,
+            skip_parse: inner_attr.skip.as_ref().map_or(false, |s| s.parse),
+            skip_display: inner_attr.skip.as_ref().map_or(false, |s| s.display),
+            doc: None,
+        };
+
+        if let (Some(styles), false) = (&outer_attr.accept_styles, renamed) {
+            for style in &styles.0 {
+                // styles are named with a string, so everything else comes from `format`
+                let string = match format {
+                    Some(f) => f.format_as(style, &ident_str),
+                    None => style.format(&ident_str, Default::default()),
+                };
+                // different styles can end up with the same string, that's fine
+                let in_from_str = this.iter().any(|s| s.string == string);
+                let in_serde = this.iter_scope(Scope::Serde).any(|s| s.string == string);
+                let scope = match (in_from_str, in_serde) {
+                    (true, true) => continue,
+                    (true, false) => Some(Scope::Serde),
+                    (false, true) => Some(Scope::FromStr),
+                    (false, false) => None,
+                };
+                this.styled.push((SpannedString::new(string, span), scope));
+            }
+        }
+        this
+    }
+    /// `variant` only accepting `string`, without any aliases or parse modes
+    pub fn exact(variant: &Self, string: SpannedString) -> Self {
+        Self {
+            original: variant.original.clone(),
+            cfg: variant.cfg.clone(),
+            formatted: string,
+            aliases: Vec::new(),
+            styled: Vec::new(),
+            deprecated: Vec::new(),
+            mode: ParseMode::default(),
+            other: false,
+            skip_parse: variant.skip_parse,
+            skip_display: variant.skip_display,
+            doc: None,
         }
     }
+    /// every string accepted by `FromStr`
+    #[inline]
     pub fn iter(&self) -> impl Iterator<Item = &SpannedString> {
-        self.aliases.iter().chain(std::iter::once(&self.formatted))
+        self.iter_scope(Scope::FromStr)
+    }
+    pub fn iter_scope(&self, scope: Scope) -> impl Iterator<Item = &SpannedString> {
+        self.iter_kinds(scope).map(|(s, _)| s)
+    }
+    /// every string accepted on `scope`, with the name of its `MatchKind`
+    pub fn iter_kinds(&self, scope: Scope) -> impl Iterator<Item = (&SpannedString, &'static str)> {
+        std::iter::once((&self.formatted, "Primary"))
+            .chain(
+                self.aliases
+                    .iter()
+                    .filter(move |a| a.1.map_or(true, |s| s == scope))
+                    .map(|a| (&a.0, "Alias")),
+            )
+            .chain(
+                self.styled
+                    .iter()
+                    .filter(move |s| s.1.map_or(true, |s| s == scope))
+                    .map(|s| (&s.0, "Alias")),
+            )
+            .chain(self.deprecated.iter().map(|s| (s, "Deprecated")))
+            .filter(move |_| !self.skip_parse)
+    }
+    /// strings listed in `__STRS` and error messages, leaving out deprecated aliases
+    pub fn iter_listed(&self, scope: Scope) -> impl Iterator<Item = &SpannedString> {
+        self.iter_kinds(scope)
+            .filter(|(_, kind)| *kind != "Deprecated")
+            .map(|(s, _)| s)
+    }
+    /// the string to display, the placeholder if it has `skip(display)`
+    pub fn display_str<'a>(&'a self, outer_attr: &'a OuterAttrs) -> Option<&'a SpannedString> {
+        if self.skip_display {
+            outer_attr.placeholder.as_ref().map(|p| &p.0)
+        } else {
+            Some(&self.formatted)
+        }
+    }
+    #[inline]
+    pub fn has_scoped_aliases(&self) -> bool {
+        self.aliases.iter().any(|a| a.1.is_some()) || self.styled.iter().any(|s| s.1.is_some())
     }
 }
 
@@ -94,10 +321,28 @@ pub enum Impl {
     TryFromString,
     Serialize,
     Deserialize,
+    AsStr,
+    FromStrConst,
+    FromBytes,
+    TryFromBytes,
+    TryFromOsStr,
+    ParsePrefix,
+    ParseWithInfo,
+    Names,
+    ValueEnum,
+    JsonSchema,
+    ToSql,
+    FromSql,
+    Type,
+    Encode,
+    Decode,
 }
 
 impl Impl {
-    pub const fn default() -> [Self; 7] {
+    /// implemented with `sqlx`
+    pub const SQLX: [Self; 3] = [Self::Type, Self::Encode, Self::Decode];
+
+    pub const fn default() -> [Self; 18] {
         [
             Self::AsRefStr,
             Self::IntoString,
@@ -106,50 +351,168 @@ impl Impl {
             Self::TryFromString,
             Self::Serialize,
             Self::Deserialize,
+            Self::AsStr,
+            Self::FromStrConst,
+            Self::FromBytes,
+            Self::TryFromBytes,
+            Self::TryFromOsStr,
+            Self::ParsePrefix,
+            Self::ParseWithInfo,
+            Self::Names,
+            Self::JsonSchema,
+            Self::ToSql,
+            Self::FromSql,
         ]
     }
 
-    pub fn default_impl(typ: &Ident, formatted: &[FormattedVariant]) -> TokenStream {
-        let mut as_str = TokenStream::new();
-        let mut from_str = TokenStream::new();
+    /// if it needs the string of every variant
+    pub fn needs_display(self, outer_attr: &OuterAttrs) -> bool {
+        match self {
+            Self::AsRefStr
+            | Self::IntoString
+            | Self::Display
+            | Self::AsStr
+            | Self::ToSql
+            | Self::Encode => true,
+            // numeric reprs don't use the strings
+            Self::Serialize => outer_attr.serde.is_none(),
+            _ => false,
+        }
+    }
 
-        for f in formatted {
-            let ident = &f.original;
-            let formatted = &f.formatted;
+    pub fn default_impl(
+        typ: &Typ,
+        formatted: &[FormattedVariant],
+        outer_attr: &OuterAttrs,
+    ) -> TokenStream {
+        let name = typ.ident.to_string();
+        let krate = outer_attr.crate_path();
+        let (impl_generics, _, where_clause) = typ.generics.split_for_impl();
+        let mut as_str = TokenStream::new();
+        if is_displayable(formatted, outer_attr) {
+            let constness = quote_const(&typ.generics);
+            let idents = formatted.iter().map(|f| &f.original);
+            let cfgs = formatted.iter().map(|f| &f.cfg);
+            let strings = formatted.iter().map(|f| f.display_str(outer_attr));
+            as_str = quote! {
+                #[doc(hidden)]
+                #constness fn __as_str(&self) -> &'static str {
+                    match self {
+                        #(#cfgs Self::#idents => #strings,)*
+                    }
+                }
+            };
+        }
+        let other = formatted.iter().find(|f| f.other).map(|f| &f.original);
+        let strs = quote_strs(formatted, Scope::FromStr);
+        let (from_str, from_str_normalized) =
+            quote_from_str(formatted, Scope::FromStr, outer_attr, quote! { Ok }, true);
 
-            as_str.extend(quote! {
-                Self::#ident => #formatted,
-            });
+        // serde only gets its own table if it accepts different strings
+        let mut serde = TokenStream::new();
+        if formatted.iter().any(FormattedVariant::has_scoped_aliases) {
+            let strs = quote_strs(formatted, Scope::Serde);
+            let (from_str, from_str_normalized) = quote_from_str(
+                formatted,
+                Scope::Serde,
+                outer_attr,
+                quote! { ::core::option::Option::Some },
+                false,
+            );
+            let fallback = match other {
+                Some(other) => quote! { ::core::option::Option::Some(Self::#other) },
+                None => quote! { ::core::option::Option::None },
+            };
+            serde = quote! {
+                #[doc(hidden)]
+                const __SERDE_STRS: &'static [&'static str] = &#strs;
+                #[doc(hidden)]
+                fn __from_str_serde(s: &str) -> ::core::option::Option<Self> {
+                    if let ::core::option::Option::Some(v) = #from_str {
+                        return ::core::option::Option::Some(v);
+                    }
+                    #from_str_normalized
+                    #fallback
+                }
+            };
+        }
 
-            let strings = f.iter();
-            from_str.extend(quote! {
-                #(#strings )|* => Self::#ident,
+        let mut error = quote! {
+            #krate::ParseEnumError::__new(#name, s)
+        };
+        let mut suggest = TokenStream::new();
+        if outer_attr.suggest {
+            error.extend(quote! {
+                .__with_suggestion(Self::closest_match(s))
             });
+            suggest = quote! {
+                /// Returns the accepted string closest to `s`, if there's one close enough.
+                pub fn closest_match(s: &str) -> ::core::option::Option<&'static str> {
+                    #krate::__internal::closest_match(s, Self::__STRS)
+                }
+            };
         }
 
+        let fallback = match other {
+            Some(other) => quote! { Ok((Self::#other, #krate::MatchKind::Other)) },
+            None => quote! { Err(#error) },
+        };
+        let invalid_utf8 = match other {
+            Some(other) => quote! { Ok(Self::#other) },
+            None => quote! { Err(#krate::ParseEnumError::__from_bytes(#name, b)) },
+        };
+        let (repr_check, eval_repr_check) = quote_serde_repr_check(typ, formatted, outer_attr);
+
         quote! {
-            impl #typ {
+            #eval_repr_check
+            impl #impl_generics #typ #where_clause {
                 #[doc(hidden)]
-                fn __as_str(&self) -> &'static str {
-                    match self {
-                        #as_str
+                const __STRS: &'static [&'static str] = &#strs;
+                #repr_check
+                #as_str
+                #[doc(hidden)]
+                fn __from_str_info(s: &str) -> ::core::result::Result<(Self, #krate::MatchKind), #krate::ParseEnumError> {
+                    if let ::core::option::Option::Some(v) = #from_str {
+                        return Ok(v);
                     }
+                    #from_str_normalized
+                    #fallback
                 }
                 #[doc(hidden)]
-                fn __from_str(s: &str) -> ::core::result::Result<Self, ()> {
-                    Ok(match s {
-                        #from_str
-                        _ => return Err(())
-                    })
+                #[inline]
+                fn __from_str(s: &str) -> ::core::result::Result<Self, #krate::ParseEnumError> {
+                    Self::__from_str_info(s).map(|(v, _)| v)
+                }
+                #serde
+                #[doc(hidden)]
+                fn __from_bytes(b: &[u8]) -> ::core::result::Result<Self, #krate::ParseEnumError> {
+                    match ::core::str::from_utf8(b) {
+                        Ok(s) => Self::__from_str(s),
+                        // no variant can match invalid utf-8
+                        Err(_) => #invalid_utf8,
+                    }
                 }
+                #suggest
             }
         }
     }
 
-    pub fn quote_impl(self, typ: &Ident) -> TokenStream {
+    pub fn quote_impl(
+        self,
+        typ: &Typ,
+        formatted: &[FormattedVariant],
+        outer_attr: &OuterAttrs,
+    ) -> TokenStream {
+        let krate = outer_attr.crate_path();
+        let (error, map_err) = quote_error(outer_attr);
+        let from_str = quote! { Self::__from_str(s) #map_err };
+        let (impl_generics, ty_generics, where_clause) = typ.generics.split_for_impl();
+        let impl_generics_a = typ.impl_generics_with("'a");
+        let impl_generics_de = typ.impl_generics_with("'de");
+
         match self {
             Self::AsRefStr => quote! {
-                impl ::core::convert::AsRef<str> for #typ {
+                impl #impl_generics ::core::convert::AsRef<str> for #typ #where_clause {
                     #[inline]
                     fn as_ref(&self) -> &'static str {
                         self.__as_str()
@@ -159,10 +522,10 @@ impl Impl {
 
             Self::IntoString => {
                 quote! {
-                    fieldless_enum_tools::if_alloc_enabled! { const _: () = {
-                        use ::fieldless_enum_tools::__internal::String;
+                    #krate::if_alloc_enabled! { const _: () = {
+                        use #krate::__internal::String;
 
-                        impl ::core::convert::Into<String> for #typ {
+                        impl #impl_generics ::core::convert::Into<String> for #typ #where_clause {
                             #[inline]
                             fn into(self) -> String {
                                 self.__as_str().to_owned()
@@ -174,7 +537,7 @@ impl Impl {
             }
 
             Self::Display => quote! {
-                impl ::core::fmt::Display for #typ {
+                impl #impl_generics ::core::fmt::Display for #typ #where_clause {
                     #[inline]
                     fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                         f.write_str(self.__as_str())
@@ -183,27 +546,28 @@ impl Impl {
             },
 
             Self::FromStr => quote! {
-                impl ::core::str::FromStr for #typ {
-                    type Err = ();
+                impl #impl_generics ::core::str::FromStr for #typ #where_clause {
+                    type Err = #error;
 
                     #[inline]
-                    fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
-                       Self::__from_str(s)
+                    fn from_str(s: &str) -> ::core::result::Result<Self, #error> {
+                        #from_str
                     }
                 }
             },
 
             Self::TryFromString => {
                 quote! {
-                    ::fieldless_enum_tools::if_alloc_enabled! { const _: () = {
-                        use ::fieldless_enum_tools::__internal::String;
+                    #krate::if_alloc_enabled! { const _: () = {
+                        use #krate::__internal::String;
 
-                        impl ::core::convert::TryFrom<String> for #typ {
-                            type Error = ();
+                        impl #impl_generics ::core::convert::TryFrom<String> for #typ #where_clause {
+                            type Error = #error;
 
                             #[inline]
-                            fn try_from(s: String) -> ::core::result::Result<Self, ()> {
-                                Self::__from_str(&s)
+                            fn try_from(s: String) -> ::core::result::Result<Self, #error> {
+                                let s = s.as_str();
+                                #from_str
                             }
                         }
                         };
@@ -212,13 +576,29 @@ impl Impl {
             }
 
             Self::Serialize => {
+                let serialize = match outer_attr.serde.as_ref().map(|s| &s.repr) {
+                    Some(repr) => {
+                        let (ty, values, check) = quote_serde_repr(typ, repr, formatted);
+                        let idents = formatted.iter().map(|f| &f.original);
+                        let cfgs = formatted.iter().map(|f| &f.cfg);
+                        quote! {
+                            #check
+                            let v: #ty = match self {
+                                #(#cfgs Self::#idents => #values,)*
+                            };
+                            v.serialize(ser)
+                        }
+                    }
+                    None => quote! { self.__as_str().serialize(ser) },
+                };
+
                 quote! {
-                    ::fieldless_enum_tools::if_serde_enabled! {const _: () = {
-                        use ::fieldless_enum_tools::__internal::serde;
+                    #krate::if_serde_enabled! {const _: () = {
+                        use #krate::__internal::serde;
 
-                        impl serde::Serialize for #typ {
+                        impl #impl_generics serde::Serialize for #typ #where_clause {
                             fn serialize<S: serde::Serializer>(&self, ser: S) -> ::core::result::Result<S::Ok, S::Error> {
-                                self.__as_str().serialize(ser)
+                                #serialize
                             }
                         }
                         };
@@ -227,32 +607,644 @@ impl Impl {
             }
 
             Self::Deserialize => {
-                let visitor = quote::format_ident!("__{}Visitor", typ);
+                use attrs::outer::SerdeRepr;
+
+                let visitor = quote::format_ident!("__{}Visitor", typ.ident);
+                let from_str = if formatted.iter().any(FormattedVariant::has_scoped_aliases) {
+                    quote! { <#typ>::__from_str_serde(v).ok_or_else(|| E::unknown_variant(v, <#typ>::__SERDE_STRS)) }
+                } else {
+                    quote! { <#typ>::__from_str(v).map_err(|_| E::unknown_variant(v, <#typ>::__STRS)) }
+                };
+                let visit_str = quote! {
+                    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                        #from_str
+                    }
+
+                    // `visit_string` already forwards here, and only exists if serde has `alloc`
+                    fn visit_borrowed_str<E: serde::de::Error>(self, v: &'de str) -> Result<Self::Value, E> {
+                        self.visit_str(v)
+                    }
+
+                    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                        match ::core::str::from_utf8(v) {
+                            Ok(s) => self.visit_str(s),
+                            Err(_) => Err(E::invalid_value(serde::de::Unexpected::Bytes(v), &self)),
+                        }
+                    }
+                };
+                let one_of = {
+                    let strs: Vec<_> = formatted
+                        .iter()
+                        .flat_map(|f| f.iter_listed(Scope::Serde))
+                        .map(|s| format!("`{}`", s.string))
+                        .collect();
+                    match strs.len() {
+                        0 => "nothing".to_owned(),
+                        1 => strs[0].clone(),
+                        2 => format!("{} or {}", strs[0], strs[1]),
+                        _ => format!("one of {}", strs.join(", ")),
+                    }
+                };
+
+                let mut items = TokenStream::new();
+                let (expecting, visit, deserialize) = match outer_attr.serde {
+                    Some(ref serde) => {
+                        let expecting = match serde.repr {
+                            SerdeRepr::Int(ref ty) => {
+                                format!("an {} discriminant of {}", ty, typ.ident)
+                            }
+                            SerdeRepr::Index => format!("a variant index of {}", typ.ident),
+                        };
+                        let (ty, values, check) = quote_serde_repr(typ, &serde.repr, formatted);
+                        let idents = formatted.iter().map(|f| &f.original);
+                        let cfgs = formatted.iter().map(|f| &f.cfg);
+                        // unknown integers, even out of range ones, become `other` like strings do
+                        let or_other = formatted.iter().find(|f| f.other).map(|f| {
+                            let other = &f.original;
+                            quote! { .or(::core::option::Option::Some(<#typ>::#other)) }
+                        });
+
+                        let visit = quote! {
+                            fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<Self::Value, E> {
+                                <#ty as ::core::convert::TryFrom<u64>>::try_from(v)
+                                    .ok()
+                                    .and_then(<#visitor #ty_generics>::from_repr)
+                                    #or_other
+                                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Unsigned(v), &self))
+                            }
+
+                            fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<Self::Value, E> {
+                                <#ty as ::core::convert::TryFrom<i64>>::try_from(v)
+                                    .ok()
+                                    .and_then(<#visitor #ty_generics>::from_repr)
+                                    #or_other
+                                    .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Signed(v), &self))
+                            }
+                        };
+                        items = quote! {
+                            impl #impl_generics #visitor #ty_generics #where_clause {
+                                fn from_repr(v: #ty) -> ::core::option::Option<#typ> {
+                                    #check
+                                    #(#cfgs if v == #values {
+                                        return ::core::option::Option::Some(<#typ>::#idents);
+                                    })*
+                                    ::core::option::Option::None
+                                }
+                            }
+                        };
+
+                        if serde.lenient {
+                            (
+                                format!("{} or {}", one_of, expecting),
+                                quote! { #visit #visit_str },
+                                // only self describing formats can tell which one it is
+                                quote! { deserialize_any },
+                            )
+                        } else {
+                            let deserialize = match serde.repr {
+                                SerdeRepr::Int(ref ty) => {
+                                    quote::format_ident!("deserialize_{}", ty)
+                                }
+                                SerdeRepr::Index => quote::format_ident!("deserialize_u32"),
+                            };
+                            (expecting, visit, quote! { #deserialize })
+                        }
+                    }
+                    None => (one_of, visit_str, quote! { deserialize_str }),
+                };
+
                 quote! {
-                        ::fieldless_enum_tools::if_serde_enabled! { const _: () = {
-                            use ::fieldless_enum_tools::__internal::serde;
-                            impl<'de> serde::Deserialize<'de> for #typ {
+                        #krate::if_serde_enabled! { const _: () = {
+                            use #krate::__internal::serde;
+                            impl #impl_generics_de serde::Deserialize<'de> for #typ #where_clause {
                                 fn deserialize<D: serde::Deserializer<'de>>(de: D) -> ::core::result::Result<Self, D::Error> {
-                                    struct #visitor;
-                                    impl<'de> serde::de::Visitor<'de> for #visitor {
+                                    // items can't use the outer generics, so the visitor declares its own
+                                    struct #visitor #impl_generics (::core::marker::PhantomData<#typ>) #where_clause;
+                                    #items
+                                    impl #impl_generics_de serde::de::Visitor<'de> for #visitor #ty_generics #where_clause {
                                         type Value = #typ;
 
                                         fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
-                                            f.write_str(concat!["an ", stringify!(#typ)])
+                                            f.write_str(#expecting)
                                         }
 
-                                        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E>{
-                                            <#typ>::__from_str(v).map_err(|_| E::invalid_value(serde::de::Unexpected::Str(v), &self))
-                                        }
+                                        #visit
                                     }
 
-                                    de.deserialize_str(#visitor)
+                                    de.#deserialize(#visitor(::core::marker::PhantomData))
+                                }
+                            }
+                        };
+                    }
+                }
+            }
+
+            Self::FromBytes => quote! {
+                impl #impl_generics #typ #where_clause {
+                    /// Parses an variant from bytes, the same way as [`FromStr`](::core::str::FromStr).
+                    #[inline]
+                    pub fn from_bytes(b: &[u8]) -> ::core::result::Result<Self, #error> {
+                        Self::__from_bytes(b) #map_err
+                    }
+                }
+            },
+
+            Self::TryFromBytes => quote! {
+                impl #impl_generics_a ::core::convert::TryFrom<&'a [u8]> for #typ #where_clause {
+                    type Error = #error;
+
+                    #[inline]
+                    fn try_from(b: &'a [u8]) -> ::core::result::Result<Self, #error> {
+                        Self::__from_bytes(b) #map_err
+                    }
+                }
+            },
+
+            Self::TryFromOsStr => {
+                let name = typ.ident.to_string();
+                let invalid_utf8 = match formatted.iter().find(|f| f.other) {
+                    Some(other) => {
+                        let other = &other.original;
+                        quote! { Ok(Self::#other) }
+                    }
+                    None => quote! {
+                        Err(#krate::ParseEnumError::__new(#name, &s.to_string_lossy())) #map_err
+                    },
+                };
+                quote! {
+                    #krate::if_std_enabled! { const _: () = {
+                        use #krate::__internal::OsStr;
+
+                        impl #impl_generics_a ::core::convert::TryFrom<&'a OsStr> for #typ #where_clause {
+                            type Error = #error;
+
+                            #[inline]
+                            fn try_from(s: &'a OsStr) -> ::core::result::Result<Self, #error> {
+                                match s.to_str() {
+                                    Some(s) => #from_str,
+                                    None => #invalid_utf8,
                                 }
                             }
+                        }
                         };
                     }
                 }
             }
+
+            Self::ParsePrefix => {
+                let mut arms: Vec<_> = formatted
+                    .iter()
+                    .flat_map(|f| f.iter().map(move |s| (s, &f.original, &f.cfg)))
+                    .collect();
+                // longest strings first, so the longest match wins
+                arms.sort_by_key(|a| std::cmp::Reverse(a.0.string.len()));
+                let strings = arms.iter().map(|a| a.0);
+                let idents = arms.iter().map(|a| a.1);
+                let cfgs = arms.iter().map(|a| a.2);
+
+                quote! {
+                    impl #impl_generics #typ #where_clause {
+                        /// Parses the longest variant string (or alias) at the start of `s`,
+                        /// returning it and the rest of `s`.
+                        pub fn parse_prefix(s: &str) -> ::core::option::Option<(Self, &str)> {
+                            #(#cfgs if let ::core::option::Option::Some(rest) = s.strip_prefix(#strings) {
+                                return ::core::option::Option::Some((Self::#idents, rest));
+                            })*
+                            ::core::option::Option::None
+                        }
+                    }
+                }
+            }
+
+            Self::ParseWithInfo => quote! {
+                impl #impl_generics #typ #where_clause {
+                    /// Parses an variant the same way as [`FromStr`](::core::str::FromStr),
+                    /// also returning which kind of string matched.
+                    #[inline]
+                    pub fn parse_with_info(s: &str) -> ::core::result::Result<(Self, #krate::MatchKind), #error> {
+                        Self::__from_str_info(s) #map_err
+                    }
+                }
+            },
+
+            Self::Names => {
+                let names = quote_cfg_array(
+                    formatted
+                        .iter()
+                        .filter(|f| !f.skip_parse)
+                        .map(|f| (&f.cfg, f.formatted.to_token_stream())),
+                );
+                let accepted = quote_cfg_array(formatted.iter().flat_map(|f| {
+                    let ident = &f.original;
+                    f.iter()
+                        .map(move |s| (&f.cfg, quote! { (#s, Self::#ident) }))
+                }));
+
+                quote! {
+                    impl #impl_generics #typ #where_clause {
+                        /// The main string of every variant, in declaration order.
+                        pub const NAMES: &'static [&'static str] = &#names;
+                        /// Every accepted string, including aliases, and the variant it parses into.
+                        pub const ACCEPTED: &'static [(&'static str, Self)] = &#accepted;
+                    }
+                }
+            }
+
+            Self::ValueEnum => {
+                // clap expects a possible value for every listed variant
+                let variants =
+                    quote_cfg_array(formatted.iter().filter(|f| !f.skip_parse).map(|f| {
+                        let ident = &f.original;
+                        (&f.cfg, quote! { Self::#ident })
+                    }));
+                let mut arms = TokenStream::new();
+                for f in formatted {
+                    let ident = &f.original;
+                    let cfg = &f.cfg;
+                    let value = if f.skip_parse {
+                        quote! { ::core::option::Option::None }
+                    } else {
+                        let name = &f.formatted;
+                        let aliases = f.iter().skip(1);
+                        let help = f.doc.as_ref().map(|doc| quote! { .help(#doc) });
+                        quote! {
+                            ::core::option::Option::Some(
+                                ::clap::builder::PossibleValue::new(#name)
+                                    #(.alias(#aliases))*
+                                    #help
+                            )
+                        }
+                    };
+                    arms.extend(quote! {
+                        #cfg
+                        Self::#ident => #value,
+                    });
+                }
+
+                quote! {
+                    // clap is a dependency of the crate opting in, since this one
+                    // can't depend on clap 4 without raising its MSRV
+                    const _: () = {
+                        impl #impl_generics #typ #where_clause {
+                            // only constants can be borrowed for any lifetime
+                            const __VALUE_VARIANTS: &'static [Self] = &#variants;
+                        }
+
+                        impl #impl_generics ::clap::ValueEnum for #typ #where_clause {
+                            fn value_variants<'a>() -> &'a [Self] {
+                                Self::__VALUE_VARIANTS
+                            }
+                            fn to_possible_value(&self) -> ::core::option::Option<::clap::builder::PossibleValue> {
+                                match self {
+                                    #arms
+                                }
+                            }
+                        }
+                    };
+                }
+            }
+
+            Self::JsonSchema => {
+                let name = typ.ident.to_string();
+                // everything `Serialize` writes, even the strings of `skip(parse)` variants
+                let (ty, values): (_, Vec<TokenStream>) =
+                    match outer_attr.serde.as_ref().map(|s| &s.repr) {
+                        Some(repr) => ("integer", quote_serde_repr(typ, repr, formatted).1),
+                        None => (
+                            "string",
+                            formatted
+                                .iter()
+                                .map(|f| match f.display_str(outer_attr) {
+                                    Some(s) => s.to_token_stream(),
+                                    None => f.formatted.to_token_stream(),
+                                })
+                                .collect(),
+                        ),
+                    };
+
+                // every string deserializes into the `other` variant
+                let schema = if formatted.iter().any(|f| f.other) && outer_attr.serde.is_none() {
+                    quote! { schemars::json_schema!({ "type": #ty }) }
+                } else if formatted.iter().any(|f| f.doc.is_some()) {
+                    let variants =
+                        quote_cfg_array(formatted.iter().zip(&values).map(|(f, value)| {
+                            let description =
+                                f.doc.as_ref().map(|doc| quote! { "description": #doc, });
+                            (
+                                &f.cfg,
+                                quote! { schemars::json_schema!({ #description "const": #value }) },
+                            )
+                        }));
+                    quote! {
+                        let variants = #variants;
+                        schemars::json_schema!({ "type": #ty, "oneOf": &variants[..] })
+                    }
+                } else {
+                    let values = quote_cfg_array(
+                        formatted
+                            .iter()
+                            .zip(&values)
+                            .map(|(f, v)| (&f.cfg, v.clone())),
+                    );
+                    quote! {
+                        let values = #values;
+                        schemars::json_schema!({ "type": #ty, "enum": &values[..] })
+                    }
+                };
+                let schema = match outer_attr.doc {
+                    Some(ref doc) => quote! {
+                        let mut schema = { #schema };
+                        schema.insert("description".into(), #doc.into());
+                        schema
+                    },
+                    None => schema,
+                };
+
+                quote! {
+                    #krate::if_schemars_enabled! { const _: () = {
+                        use #krate::__internal::schemars;
+
+                        impl #impl_generics schemars::JsonSchema for #typ #where_clause {
+                            fn schema_name() -> #krate::__internal::Cow<'static, str> {
+                                ::core::convert::Into::into(#name)
+                            }
+                            fn schema_id() -> #krate::__internal::Cow<'static, str> {
+                                ::core::convert::Into::into(::core::concat!(::core::module_path!(), "::", #name))
+                            }
+                            fn json_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+                                #schema
+                            }
+                        }
+                    };}
+                }
+            }
+
+            Self::ToSql => quote! {
+                #krate::if_rusqlite_enabled! { const _: () = {
+                    use #krate::__internal::rusqlite::{self, types::{ToSqlOutput, ValueRef}};
+
+                    impl #impl_generics rusqlite::ToSql for #typ #where_clause {
+                        #[inline]
+                        fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+                            Ok(ToSqlOutput::Borrowed(ValueRef::Text(self.__as_str().as_bytes())))
+                        }
+                    }
+                };}
+            },
+
+            Self::FromSql => quote! {
+                #krate::if_rusqlite_enabled! { const _: () = {
+                    use #krate::__internal::rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ValueRef};
+
+                    impl #impl_generics FromSql for #typ #where_clause {
+                        fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+                            Self::__from_str(value.as_str()?)
+                                .map_err(|e| FromSqlError::Other(::core::convert::From::from(e)))
+                        }
+                    }
+                };}
+            },
+
+            // like clap, sqlx is a dependency of the crate opting in, since sqlx-sqlite
+            // can't be resolved by the MSRV nor linked without picking how for the user
+            Self::Type => quote! {
+                const _: () = {
+                    use ::sqlx::{Database, Sqlite, Type};
+
+                    impl #impl_generics Type<Sqlite> for #typ #where_clause {
+                        #[inline]
+                        fn type_info() -> <Sqlite as Database>::TypeInfo {
+                            <str as Type<Sqlite>>::type_info()
+                        }
+                        #[inline]
+                        fn compatible(ty: &<Sqlite as Database>::TypeInfo) -> bool {
+                            <str as Type<Sqlite>>::compatible(ty)
+                        }
+                    }
+                };
+            },
+
+            Self::Encode => {
+                let impl_generics_q = typ.impl_generics_with("'q");
+                quote! {
+                    const _: () = {
+                        use ::sqlx::{encode::IsNull, error::BoxDynError, Database, Encode, Sqlite};
+
+                        impl #impl_generics_q Encode<'q, Sqlite> for #typ #where_clause {
+                            #[inline]
+                            fn encode_by_ref(
+                                &self,
+                                buf: &mut <Sqlite as Database>::ArgumentBuffer<'q>,
+                            ) -> ::core::result::Result<IsNull, BoxDynError> {
+                                <&str as Encode<'q, Sqlite>>::encode_by_ref(&self.__as_str(), buf)
+                            }
+                        }
+                    };
+                }
+            }
+
+            Self::Decode => {
+                let impl_generics_r = typ.impl_generics_with("'r");
+                quote! {
+                    const _: () = {
+                        use ::sqlx::{error::BoxDynError, Database, Decode, Sqlite};
+
+                        impl #impl_generics_r Decode<'r, Sqlite> for #typ #where_clause {
+                            fn decode(
+                                value: <Sqlite as Database>::ValueRef<'r>,
+                            ) -> ::core::result::Result<Self, BoxDynError> {
+                                let s = <&str as Decode<'r, Sqlite>>::decode(value)?;
+                                Ok(Self::__from_str(s)?)
+                            }
+                        }
+                    };
+                }
+            }
+
+            Self::AsStr => {
+                let (vis, name) = attrs::outer::ConstFn::vis_and_name(&outer_attr.as_str, "as_str");
+                let constness = quote_const(&typ.generics);
+                quote! {
+                    impl #impl_generics #typ #where_clause {
+                        /// Returns the string this variant is formatted as.
+                        #[inline]
+                        #vis #constness fn #name(&self) -> &'static str {
+                            self.__as_str()
+                        }
+                    }
+                }
+            }
+
+            Self::FromStrConst => {
+                let (vis, name) = attrs::outer::ConstFn::vis_and_name(
+                    &outer_attr.from_str_const,
+                    "from_str_const",
+                );
+                let mut ifs = TokenStream::new();
+                for f in formatted {
+                    let ident = &f.original;
+                    let cfg = &f.cfg;
+                    let strings = f.iter();
+                    ifs.extend(quote! {
+                        #(#cfg if #krate::__internal::eq_bytes(s, #strings.as_bytes()) {
+                            return ::core::option::Option::Some(Self::#ident);
+                        })*
+                    });
+                }
+                let constness = quote_const(&typ.generics);
+
+                quote! {
+                    impl #impl_generics #typ #where_clause {
+                        /// Parses an variant from an exact match of its string or aliases, usable in const contexts.
+                        #vis #constness fn #name(s: &str) -> ::core::option::Option<Self> {
+                            let s = s.as_bytes();
+                            #ifs
+                            ::core::option::Option::None
+                        }
+                    }
+                }
+            }
         }
     }
 }
+
+/// type and value of every variant on `Serialize` and `Deserialize`,
+/// plus a statement checking that every discriminant fits the type
+fn quote_serde_repr(
+    typ: &Typ,
+    repr: &attrs::outer::SerdeRepr,
+    formatted: &[FormattedVariant],
+) -> (TokenStream, Vec<TokenStream>, TokenStream) {
+    use attrs::outer::SerdeRepr;
+
+    match repr {
+        SerdeRepr::Int(ty) => (
+            quote! { #ty },
+            formatted
+                .iter()
+                .map(|f| {
+                    let ident = &f.original;
+                    quote! { <#typ>::#ident as #ty }
+                })
+                .collect(),
+            // generic enums only evaluate it here, the others already did in `default_impl`
+            quote! { let () = <#typ>::__SERDE_REPR_FITS; },
+        ),
+        SerdeRepr::Index => (
+            quote! { u32 },
+            (0..formatted.len() as u32).map(|i| quote! { #i }).collect(),
+            TokenStream::new(),
+        ),
+    }
+}
+
+/// An associated const that fails to evaluate if a discriminant doesn't fit the `serde(repr)`
+/// integer, instead of letting `as` truncate it, and a `const` evaluating it for non generic enums
+fn quote_serde_repr_check(
+    typ: &Typ,
+    formatted: &[FormattedVariant],
+    outer_attr: &OuterAttrs,
+) -> (TokenStream, TokenStream) {
+    use attrs::outer::SerdeRepr;
+
+    let ty = match outer_attr.serde.as_ref().map(|s| &s.repr) {
+        Some(SerdeRepr::Int(ty)) => ty,
+        _ => return (TokenStream::new(), TokenStream::new()),
+    };
+    let checks = formatted.iter().map(|f| {
+        let ident = &f.original;
+        let cfg = &f.cfg;
+        // indexing out of bounds is the only way to fail a const before `assert!` works in one
+        quote::quote_spanned! {ident.span()=>
+            #cfg
+            let () = [()][(Self::#ident as i128 != Self::#ident as #ty as i128) as usize];
+        }
+    });
+    let assoc = quote! {
+        #[doc(hidden)]
+        const __SERDE_REPR_FITS: () = { #(#checks)* };
+    };
+    let eval = if typ.generics.params.is_empty() {
+        quote! { const _: () = <#typ>::__SERDE_REPR_FITS; }
+    } else {
+        TokenStream::new()
+    };
+    (assoc, eval)
+}
+
+/// Quotes an array of every listed string on `scope`
+fn quote_strs(formatted: &[FormattedVariant], scope: Scope) -> TokenStream {
+    quote_cfg_array(
+        formatted
+            .iter()
+            .flat_map(|f| f.iter_listed(scope).map(move |s| (&f.cfg, quote! { #s }))),
+    )
+}
+
+/// Quotes the error type of the parsing functions, and how to convert a `ParseEnumError` into it.
+fn quote_error(outer_attr: &OuterAttrs) -> (TokenStream, TokenStream) {
+    let krate = outer_attr.crate_path();
+    // only convert the error if there's a custom one
+    match outer_attr.error {
+        Some(ref e) => {
+            let e = &e.0;
+            (
+                quote! { #e },
+                quote! { .map_err(::core::convert::Into::into) },
+            )
+        }
+        None => (quote! { #krate::ParseEnumError }, TokenStream::new()),
+    }
+}
+
+/// quotes the exact matcher, evaluating to `Option<Self>`,
+/// and the normalized comparisons, returning `Self` from the function
+fn quote_from_str(
+    formatted: &[FormattedVariant],
+    scope: Scope,
+    outer_attr: &OuterAttrs,
+    wrap: TokenStream,
+    with_kind: bool,
+) -> (TokenStream, TokenStream) {
+    let krate = outer_attr.crate_path();
+    let value = |ident: &Ident, kind: &str| {
+        if with_kind {
+            let kind = Ident::new(kind, Span::call_site());
+            quote! { (Self::#ident, #krate::MatchKind::#kind) }
+        } else {
+            quote! { Self::#ident }
+        }
+    };
+    let mut arms = Vec::new();
+    let mut normalized = TokenStream::new();
+
+    for f in formatted {
+        let ident = &f.original;
+        let cfg = &f.cfg;
+        arms.extend(
+            f.iter_kinds(scope)
+                .map(|(s, kind)| (s, value(ident, kind), cfg)),
+        );
+
+        if !f.mode.is_exact() {
+            let ParseMode {
+                case_insensitive,
+                ignore_separators,
+            } = f.mode;
+            let strings = f
+                .iter_kinds(scope)
+                .map(|(s, _)| f.mode.normalize(&s.string));
+            let values = f.iter_kinds(scope).map(|(_, kind)| value(ident, kind));
+            normalized.extend(quote! {
+                #(#cfg if #krate::__internal::eq_normalized(s, #strings, #case_insensitive, #ignore_separators) {
+                    return #wrap(#values);
+                })*
+            });
+        }
+    }
+
+    (
+        matcher::quote_matcher(outer_attr.matcher(), &arms),
+        normalized,
+    )
+}
@@ -0,0 +1,121 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::{format_ident, quote};
+
+use super::{
+    attrs::{outer::Attrs as OuterAttrs, Style},
+    is_displayable, quote_error, quote_from_str, FormattedVariant, Scope,
+};
+use crate::utils::{quote_const, SpannedString, Typ};
+
+/// An additional string representation, like `code` in `as_code` and `from_code`
+pub struct NamedRepr {
+    pub name: Ident,
+    /// every variant with only its string for this representation
+    pub variants: Vec<FormattedVariant>,
+}
+
+impl NamedRepr {
+    /// Collects the `declared` representations, then the ones only used on variants.
+    ///
+    /// Variants without a value get formatted with the representation's style,
+    /// or keep their main string if it has none.
+    pub fn collect(
+        fmtd: &[FormattedVariant],
+        variant_reprs: &[Vec<(Ident, SpannedString)>],
+        declared: &[(Ident, Option<Style>)],
+    ) -> Vec<Self> {
+        let mut declared: Vec<(&Ident, Option<&Style>)> = declared
+            .iter()
+            .map(|(name, format)| (name, format.as_ref()))
+            .collect();
+        for (name, _) in variant_reprs.iter().flatten() {
+            if declared.iter().all(|(n, _)| *n != name) {
+                declared.push((name, None));
+            }
+        }
+
+        let mut reprs = Vec::with_capacity(declared.len());
+        for (name, format) in declared {
+            let variants = fmtd
+                .iter()
+                .zip(variant_reprs)
+                .map(|(f, reprs)| {
+                    let explicit = reprs
+                        .iter()
+                        .find(|(n, _)| n == name)
+                        .map(|(_, s)| s.clone());
+                    let string = explicit.unwrap_or_else(|| match format {
+                        Some(format) => SpannedString::new(
+                            format.format(&f.original.to_string()),
+                            f.original.span(),
+                        ),
+                        None => f.formatted.clone(),
+                    });
+
+                    FormattedVariant::exact(f, string)
+                })
+                .collect();
+
+            reprs.push(Self {
+                name: name.clone(),
+                variants,
+            });
+        }
+        reprs
+    }
+
+    pub fn quote(&self, typ: &Typ, outer_attr: &OuterAttrs) -> TokenStream {
+        let name = typ.ident.to_string();
+        let (impl_generics, _, where_clause) = typ.generics.split_for_impl();
+        let as_fn = format_ident!("as_{}", self.name);
+        let from_fn = format_ident!("from_{}", self.name);
+        let as_doc = format!(
+            "Returns the `{}` representation of this variant.",
+            self.name
+        );
+        let from_doc = format!(
+            "Parses a variant from its exact `{}` representation.",
+            self.name
+        );
+
+        let mut as_str = TokenStream::new();
+        if is_displayable(&self.variants, outer_attr) {
+            let idents = self.variants.iter().map(|f| &f.original);
+            let cfgs = self.variants.iter().map(|f| &f.cfg);
+            let strings = self.variants.iter().map(|f| f.display_str(outer_attr));
+            let constness = quote_const(&typ.generics);
+            as_str = quote! {
+                #[doc = #as_doc]
+                pub #constness fn #as_fn(&self) -> &'static str {
+                    match self {
+                        #(#cfgs Self::#idents => #strings,)*
+                    }
+                }
+            };
+        }
+        let krate = outer_attr.crate_path();
+        let (error, map_err) = quote_error(outer_attr);
+        let (from_str, _) = quote_from_str(
+            &self.variants,
+            Scope::FromStr,
+            outer_attr,
+            quote! { ::core::option::Option::Some },
+            false,
+        );
+
+        quote! {
+            impl #impl_generics #typ #where_clause {
+                #as_str
+                #[doc = #from_doc]
+                pub fn #from_fn(s: &str) -> ::core::result::Result<Self, #error> {
+                    match #from_str {
+                        ::core::option::Option::Some(v) => Ok(v),
+                        ::core::option::Option::None => {
+                            Err(#krate::ParseEnumError::__new(#name, s)) #map_err
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,123 @@
+/// What digits do when splitting an identifier into words
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Digits {
+    /// "Http2Error" => ["Http2", "Error"]
+    Attach,
+    /// "Http2Error" => ["Http", "2", "Error"]
+    Separate,
+}
+
+impl Default for Digits {
+    fn default() -> Self {
+        Self::Attach
+    }
+}
+
+impl Digits {
+    pub fn from_str(s: &str) -> Option<Self> {
+        match s.trim() {
+            "attach" => Some(Self::Attach),
+            "separate" => Some(Self::Separate),
+            _ => None,
+        }
+    }
+}
+
+/// Splits an identifier into its words, keeping their case.
+///
+/// Words start at an uppercase letter after a non uppercase one ("TwoWords"),
+/// at the last letter of an acronym followed by a lowercase one ("HTTPRequest"),
+/// and after anything that isn't alphanumeric, like underscores, which is dropped.
+pub fn split(s: &str, digits: Digits) -> Vec<&str> {
+    let chars: Vec<(usize, char)> = s.char_indices().collect();
+    let mut words = Vec::new();
+    let mut start = None;
+
+    for (i, &(pos, ch)) in chars.iter().enumerate() {
+        if !ch.is_alphanumeric() {
+            if let Some(start) = start.take() {
+                words.push(&s[start..pos]);
+            }
+            continue;
+        }
+        let word_start = match start {
+            Some(word_start) => word_start,
+            None => {
+                start = Some(pos);
+                continue;
+            }
+        };
+
+        // there's a word going on, so the previous character is alphanumeric
+        let prev = chars[i - 1].1;
+        let next = chars.get(i + 1).map(|&(_, ch)| ch);
+        if is_boundary(prev, ch, next, digits) {
+            words.push(&s[word_start..pos]);
+            start = Some(pos);
+        }
+    }
+    if let Some(start) = start {
+        words.push(&s[start..]);
+    }
+    words
+}
+
+/// Whether `ch` starts a new word
+fn is_boundary(prev: char, ch: char, next: Option<char>, digits: Digits) -> bool {
+    if digits == Digits::Separate && prev.is_numeric() != ch.is_numeric() {
+        return true;
+    }
+    ch.is_uppercase() && (!prev.is_uppercase() || next.map_or(false, char::is_lowercase))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split() {
+        use Digits::*;
+
+        let table: &[(&str, Digits, &[&str])] = &[
+            ("Word", Attach, &["Word"]),
+            ("TwoWords", Attach, &["Two", "Words"]),
+            ("A", Attach, &["A"]),
+            ("AB", Attach, &["AB"]),
+            ("ABTest", Attach, &["AB", "Test"]),
+            ("HTTPRequest", Attach, &["HTTP", "Request"]),
+            ("RequestHTTP", Attach, &["Request", "HTTP"]),
+            ("MyHTTPRequest", Attach, &["My", "HTTP", "Request"]),
+            ("Two_Words", Attach, &["Two", "Words"]),
+            ("_Leading__Trailing_", Attach, &["Leading", "Trailing"]),
+            ("snake_case", Attach, &["snake", "case"]),
+            ("SCREAMING_CASE", Attach, &["SCREAMING", "CASE"]),
+            ("Utf8", Attach, &["Utf8"]),
+            ("Utf8", Separate, &["Utf", "8"]),
+            ("Http2Error", Attach, &["Http2", "Error"]),
+            ("Http2Error", Separate, &["Http", "2", "Error"]),
+            ("HTTP2Error", Attach, &["HTTP2", "Error"]),
+            ("HTTP2Error", Separate, &["HTTP", "2", "Error"]),
+            ("Ipv4Addr", Attach, &["Ipv4", "Addr"]),
+            ("Ipv4Addr", Separate, &["Ipv", "4", "Addr"]),
+            ("Status404", Attach, &["Status404"]),
+            ("Status404", Separate, &["Status", "404"]),
+            ("Status2xx", Attach, &["Status2xx"]),
+            ("Status2xx", Separate, &["Status", "2", "xx"]),
+            ("V2", Attach, &["V2"]),
+            ("V2Beta", Attach, &["V2", "Beta"]),
+            ("Ipv6_2", Attach, &["Ipv6", "2"]),
+            ("ŻółwŚpi", Attach, &["Żółw", "Śpi"]),
+            ("__", Attach, &[]),
+        ];
+
+        for (ident, digits, words) in table {
+            assert_eq!(
+                super::split(ident, *digits),
+                *words,
+                "{} with {:?}",
+                ident,
+                digits
+            );
+        }
+    }
+}
@@ -9,6 +9,7 @@ pub fn main(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     utils::check_enum_lt_variants("Not", 1, &data)?;
 
     let typ = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let fun = match data.variants.len() {
         // since there's only one variant, just return self again
         1 => {
@@ -26,7 +27,14 @@ pub fn main(input: syn::DeriveInput) -> syn::Result<TokenStream> {
                 }
             }
 
-            if has_not_attr {
+            // with #[cfg] either one could be missing, so they have to say what they become
+            let has_cfg = data
+                .variants
+                .iter()
+                .flat_map(|v| &v.attrs)
+                .any(|attr| attr.path.is_ident("cfg"));
+
+            if has_not_attr || has_cfg {
                 get_with_attrs(data.variants)?
             } else {
                 let mut iter = data.variants.iter().map(|p| &p.ident);
@@ -45,7 +53,7 @@ pub fn main(input: syn::DeriveInput) -> syn::Result<TokenStream> {
     };
 
     Ok(quote! {
-        impl ::core::ops::Not for #typ {
+        impl #impl_generics ::core::ops::Not for #typ #ty_generics #where_clause {
             type Output = Self;
             fn not(self) -> Self::Output {
                 #fun
@@ -55,11 +63,12 @@ pub fn main(input: syn::DeriveInput) -> syn::Result<TokenStream> {
 }
 
 pub fn get_with_attrs(vars: Punctuated<Variant, syn::token::Comma>) -> syn::Result<TokenStream> {
-    let mut idents: Vec<(Ident, Ident)> = Vec::with_capacity(vars.len());
+    let mut idents: Vec<(utils::Cfg, Ident, Ident)> = Vec::with_capacity(vars.len());
     // for every variant, check if theres a #[not] attribute, and if there is,
     // add both the variant ident and the ident in the #[not] on the vec
     for var in vars {
         let var_span = var.span();
+        let cfg = utils::Cfg::from_attrs(&var.attrs)?;
 
         let mut attr_ident: Option<Ident> = None;
         for attr in var.attrs {
@@ -69,18 +78,20 @@ pub fn get_with_attrs(vars: Punctuated<Variant, syn::token::Comma>) -> syn::Resu
             }
         }
         idents.push((
+            cfg,
             var.ident,
             attr_ident
                 .ok_or_else(|| Error::new(var_span, "variant doesn't have an #[not] attribute!"))?,
         ));
     }
 
-    let one = idents.iter().map(|a| &a.0);
-    let two = idents.iter().map(|a| &a.1);
+    let cfgs = idents.iter().map(|a| &a.0);
+    let one = idents.iter().map(|a| &a.1);
+    let two = idents.iter().map(|a| &a.2);
 
     Ok(quote! {
         match self {
-            #(Self::#one => Self::#two),*
+            #(#cfgs Self::#one => Self::#two),*
         }
     })
 }
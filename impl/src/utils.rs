@@ -1,4 +1,5 @@
 use proc_macro2::{Span, TokenStream};
+use quote::ToTokens;
 use syn::{Attribute, Data, DataEnum, Error, Fields, LitStr};
 
 #[inline]
@@ -70,6 +71,31 @@ pub fn try_get_doc(attrname: &'static str, attrs: &[Attribute]) -> syn::Result<O
     Ok(docstr)
 }
 
+/// The doc comment on an item, with each line trimmed
+pub fn get_doc_comment(attrs: &[Attribute]) -> Option<String> {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        if !attr.path.is_ident("doc") {
+            continue;
+        }
+        if let Ok(syn::Meta::NameValue(syn::MetaNameValue {
+            lit: syn::Lit::Str(s),
+            ..
+        })) = attr.parse_meta()
+        {
+            lines.push(s.value().trim().to_owned());
+        }
+    }
+
+    let doc = lines.join("\n");
+    let doc = doc.trim();
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc.to_owned())
+    }
+}
+
 #[derive(Clone)]
 pub struct SpannedString {
     pub string: String,
@@ -105,3 +131,136 @@ impl From<syn::Ident> for SpannedString {
         }
     }
 }
+
+/// The derived enum, quoted as its name plus generics, like `Enum<N>`
+pub struct Typ {
+    pub ident: syn::Ident,
+    pub generics: syn::Generics,
+}
+impl Typ {
+    #[inline]
+    pub fn new(ident: syn::Ident, generics: syn::Generics) -> Self {
+        Self { ident, generics }
+    }
+    /// `impl` generics plus `lifetime`, for impls like `impl<'de> Deserialize<'de>`
+    pub fn impl_generics_with(&self, lifetime: &str) -> TokenStream {
+        let mut generics = self.generics.clone();
+        let lifetime = syn::Lifetime::new(lifetime, Span::call_site());
+        // lifetimes have to come first
+        generics.params.insert(
+            0,
+            syn::GenericParam::Lifetime(syn::LifetimeDef::new(lifetime)),
+        );
+
+        let (impl_generics, _, _) = generics.split_for_impl();
+        impl_generics.to_token_stream()
+    }
+}
+impl quote::ToTokens for Typ {
+    #[inline]
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let (_, ty_generics, _) = self.generics.split_for_impl();
+        self.ident.to_tokens(tokens);
+        ty_generics.to_tokens(tokens);
+    }
+}
+
+/// The `#[cfg(...)]` attributes of a variant, merged into one
+#[derive(Clone, Default)]
+pub struct Cfg(Option<TokenStream>);
+impl Cfg {
+    pub fn from_attrs(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut predicates = Vec::new();
+        for attr in attrs {
+            if attr.path.is_ident("cfg") {
+                predicates.push(attr.parse_args::<TokenStream>()?);
+            }
+        }
+
+        Ok(Self(match predicates.len() {
+            0 => None,
+            1 => predicates.pop(),
+            _ => Some(quote::quote! { all(#(#predicates),*) }),
+        }))
+    }
+    #[inline]
+    pub fn is_none(&self) -> bool {
+        self.0.is_none()
+    }
+    /// `true` or `false` depending on the predicate, usable in const contexts
+    pub fn quote_enabled(&self) -> TokenStream {
+        match self.0 {
+            Some(ref predicate) => quote::quote! { cfg!(#predicate) },
+            None => quote::quote! { true },
+        }
+    }
+}
+/// Quotes the attribute, to be put on match arms, statements and items
+impl quote::ToTokens for Cfg {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        if let Some(ref predicate) = self.0 {
+            tokens.extend(quote::quote! { #[cfg(#predicate)] });
+        }
+    }
+}
+
+/// Quotes an array of `items`, leaving out the ones disabled by their cfg.
+///
+/// Array elements can't have attributes, so each element gets a macro
+/// adding it or not to the ones before, depending on its cfg.
+pub fn quote_cfg_array<'a>(items: impl IntoIterator<Item = (&'a Cfg, TokenStream)>) -> TokenStream {
+    let items: Vec<_> = items.into_iter().collect();
+    if items.iter().all(|(cfg, _)| cfg.is_none()) {
+        let items = items.iter().map(|(_, item)| item);
+        return quote::quote! { [#(#items),*] };
+    }
+
+    let name = |i: usize| quote::format_ident!("__cfg_array_{}", i);
+    let mut macros = TokenStream::new();
+    for (i, (cfg, item)) in items.iter().enumerate() {
+        let this = name(i);
+        let next = name(i + 1);
+        macros.extend(quote::quote! {
+            #cfg
+            macro_rules! #this {
+                ($($item:expr,)*) => { #next!($($item,)* #item,) };
+            }
+        });
+        if let Some(ref predicate) = cfg.0 {
+            macros.extend(quote::quote! {
+                #[cfg(not(#predicate))]
+                macro_rules! #this {
+                    ($($item:expr,)*) => { #next!($($item,)*) };
+                }
+            });
+        }
+    }
+    let first = name(0);
+    let last = name(items.len());
+
+    quote::quote! {{
+        #macros
+        macro_rules! #last {
+            ($($item:expr,)*) => { [$($item),*] };
+        }
+        #first!()
+    }}
+}
+
+/// Quotes how many of `cfgs` are enabled, usable in const contexts
+pub fn quote_cfg_len<'a>(cfgs: impl IntoIterator<Item = &'a Cfg>) -> TokenStream {
+    let enabled = cfgs.into_iter().map(Cfg::quote_enabled);
+    quote::quote! { 0 #(+ #enabled as usize)* }
+}
+
+/// Quotes `const` for the functions on the enum, unless it has trait bounds,
+/// which const fns only allow since Rust 1.61
+pub fn quote_const(generics: &syn::Generics) -> TokenStream {
+    let bounded =
+        generics.where_clause.is_some() || generics.type_params().any(|p| !p.bounds.is_empty());
+    if bounded {
+        TokenStream::new()
+    } else {
+        quote::quote! { const }
+    }
+}
@@ -0,0 +1,111 @@
+use core::fmt;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::string::String;
+
+/// Error returned when a string doesn't match any variant of a [`FromToStr`](crate::FromToStr) enum.
+///
+/// The rejected input is only kept if crate feature `std` or `alloc` is avaliable,
+/// since [`FromStr::Err`](core::str::FromStr::Err) can't borrow from the input.
+///
+/// ```rust
+/// use fieldless_enum_tools::{FromToStr, ParseEnumError};
+///
+/// #[derive(FromToStr, Debug)]
+/// enum Level {
+///     Warn,
+///     Error,
+/// }
+///
+/// let err: ParseEnumError = "Warnn".parse::<Level>().unwrap_err();
+/// assert_eq!(err.enum_name(), "Level");
+/// assert_eq!(err.input(), "Warnn");
+/// assert_eq!(err.to_string(), r#"invalid value "Warnn" for Level"#);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    enum_name: &'static str,
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    input: String,
+    suggestion: Option<&'static str>,
+}
+
+impl ParseEnumError {
+    #[doc(hidden)]
+    #[inline]
+    pub fn __new(enum_name: &'static str, input: &str) -> Self {
+        #[cfg(not(any(feature = "alloc", feature = "std")))]
+        let _ = input;
+
+        Self {
+            enum_name,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            input: String::from(input),
+            suggestion: None,
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn __from_bytes(enum_name: &'static str, input: &[u8]) -> Self {
+        #[cfg(not(any(feature = "alloc", feature = "std")))]
+        let _ = input;
+
+        Self {
+            enum_name,
+            #[cfg(any(feature = "alloc", feature = "std"))]
+            input: String::from_utf8_lossy(input).into_owned(),
+            suggestion: None,
+        }
+    }
+
+    #[doc(hidden)]
+    #[inline]
+    pub fn __with_suggestion(mut self, suggestion: Option<&'static str>) -> Self {
+        self.suggestion = suggestion;
+        self
+    }
+
+    /// Name of the enum that failed to parse.
+    #[inline]
+    pub const fn enum_name(&self) -> &'static str {
+        self.enum_name
+    }
+
+    /// The string that was rejected, lossily converted if it wasn't valid UTF-8.
+    #[cfg(any(feature = "alloc", feature = "std"))]
+    #[inline]
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// The closest accepted string to the input, if the enum has `#[fromtostr(suggest)]`.
+    #[inline]
+    pub const fn suggestion(&self) -> Option<&'static str> {
+        self.suggestion
+    }
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        #[cfg(any(feature = "alloc", feature = "std"))]
+        write!(f, "invalid value {:?} for {}", self.input, self.enum_name)?;
+
+        #[cfg(not(any(feature = "alloc", feature = "std")))]
+        write!(f, "invalid value for {}", self.enum_name)?;
+
+        match self.suggestion {
+            Some(s) => write!(f, ", did you mean {:?}?", s),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseEnumError {}
+
+/// Allows `#[fromtostr(error("()"))]`, keeping the old unit error.
+impl From<ParseEnumError> for () {
+    #[inline]
+    fn from(_: ParseEnumError) -> Self {}
+}
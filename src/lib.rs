@@ -1,9 +1,22 @@
 #![cfg_attr(not(feature = "std"), no_std)]
-#[cfg(feature = "alloc")]
+#[cfg(any(feature = "alloc", feature = "schemars"))]
 extern crate alloc;
 
+#[cfg(all(feature = "rusqlite", not(feature = "std")))]
+compile_error!("crate feature `rusqlite` needs crate feature `std`");
+
+mod error;
+mod match_kind;
+mod normalize;
+mod suggest;
+pub use error::ParseEnumError;
+pub use match_kind::MatchKind;
+
 /// Adds an function `all` to enum, returning an array with all variants of the enum
 ///
+/// Variants disabled by `#[cfg(...)]` are left out of the array.
+/// `all` is a const fn, unless the enum has trait bounds, which const fns can't have before Rust 1.61.
+///
 /// ```rust
 /// use fieldless_enum_tools::All;
 ///
@@ -76,7 +89,8 @@ pub use fieldless_enum_tools_impl::All;
 ///
 /// `#[not(...)]`
 ///
-/// Specifies which variant this variant will return when [`Not`]'ed
+/// Specifies which variant this variant will return when [`Not`]'ed.
+/// Needed on every variant if any of them has `#[cfg(...)]`.
 ///
 /// [`Not`]: `core::ops::Not`
 pub use fieldless_enum_tools_impl::Not;
@@ -85,8 +99,53 @@ pub use fieldless_enum_tools_impl::Not;
 /// [`AsRef<str>`],
 /// [`Into<String>`][^alloc],
 /// [`Display`] (therefore [`ToString`][^alloc]), [`FromStr`],
-/// [`TryFrom<String>`][^alloc],
-/// [`Serialize`][^serde] and [`Deserialize`][^serde] for enum.
+/// [`TryFrom<String>`][^alloc], [`TryFrom<&[u8]>`](core::convert::TryFrom), [`TryFrom<&OsStr>`][^std],
+/// [`Serialize`][^serde], [`Deserialize`][^serde], [`ValueEnum`][^clap] with `value_enum`, [`JsonSchema`][^schemars],
+/// [`ToSql`][^rusqlite], [`FromSql`][^rusqlite], and sqlx's [`Type`][^sqlx], [`Encode`][^sqlx]
+/// and [`Decode`][^sqlx] for sqlite with `sqlx`, for enum,
+/// plus the functions `from_bytes`, `parse_prefix`, `parse_with_info`, `as_str` and `from_str_const`,
+/// and the constants `NAMES` and `ACCEPTED`.
+/// Variants disabled by `#[cfg(...)]` aren't accepted nor suggested anywhere.
+///
+/// `parse_prefix` parses the longest variant string (or alias) at the start of the input,
+/// only accepting exact matches:
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// enum Op {
+///     #[fromtostr(rename("<"))]
+///     Less,
+///     #[fromtostr(rename("<="))]
+///     LessEq,
+/// }
+///
+/// assert_eq!(Op::parse_prefix("<= 2"), Some((Op::LessEq, " 2")));
+/// assert_eq!(Op::parse_prefix("< 2"), Some((Op::Less, " 2")));
+/// assert_eq!(Op::parse_prefix("> 2"), None);
+/// ```
+///
+/// `NAMES` has the main string of every variant, and `ACCEPTED` every accepted string with its variant,
+/// for things like shell completions:
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(format(style = "lower"))]
+/// enum Shell {
+///     #[fromtostr(aliases("sh"))]
+///     Bash,
+///     Zsh,
+/// }
+///
+/// assert_eq!(Shell::NAMES, ["bash", "zsh"]);
+/// assert_eq!(
+///     Shell::ACCEPTED,
+///     [("bash", Shell::Bash), ("sh", Shell::Bash), ("zsh", Shell::Zsh)]
+/// );
+/// ```
 ///
 ///```rust
 /// use fieldless_enum_tools::FromToStr;
@@ -110,9 +169,9 @@ pub use fieldless_enum_tools_impl::Not;
 /// let cool = CoolEnum::CoolVariantTwo;
 /// assert_eq!("Very😎Cool😎Variant😎Two".parse(), Ok(cool));
 ///
-/// assert_eq!("uncool variant :(".parse::<CoolEnum>(), Err(()));
+/// assert!("uncool variant :(".parse::<CoolEnum>().is_err());
 /// // errors because we renamed it to Very😎Cool😎Variant😎Two
-/// assert_eq!("Cool😎Variant😎Two".parse::<CoolEnum>(), Err(()));
+/// assert!("Cool😎Variant😎Two".parse::<CoolEnum>().is_err());
 ///```
 ///
 /// # Attributes
@@ -133,26 +192,377 @@ pub use fieldless_enum_tools_impl::Not;
 /// >| `Display`       | [`Display`]         |
 /// >| `Serialize`     | [`Serialize`]       |
 /// >| `Deserialize`   | [`Deserialize`]     |
+/// >| `AsStr`         | `as_str`            |
+/// >| `FromStrConst`  | `from_str_const`    |
+/// >| `FromBytes`     | `from_bytes`        |
+/// >| `TryFromBytes`  | `TryFrom<&[u8]>`    |
+/// >| `TryFromOsStr`  | [`TryFrom<&OsStr>`] |
+/// >| `ParsePrefix`   | `parse_prefix`      |
+/// >| `ParseWithInfo` | `parse_with_info`   |
+/// >| `Names`         | `NAMES` and `ACCEPTED` |
+/// >| `ValueEnum`     | [`ValueEnum`], even with `value_enum` |
+/// >| `JsonSchema`    | [`JsonSchema`]      |
+/// >| `ToSql`         | [`ToSql`]           |
+/// >| `FromSql`       | [`FromSql`]         |
+/// >| `Type`          | [`Type`], even with `sqlx` |
+/// >| `Encode`        | [`Encode`], even with `sqlx` |
+/// >| `Decode`        | [`Decode`], even with `sqlx` |
+///
+/// ---
+///
+/// `#[fromtostr(format(style = "...", separator = "..."?, digits = "..."?, prefix = "..."?, suffix = "..."?))]`
+///
+/// Format variants using specified [style](Self#possible-styles),
+/// between `prefix` and `suffix` if given
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(format(style = "snake", prefix = "ev.", suffix = ".v1"))]
+/// enum Event {
+///     UserCreated,
+///     #[fromtostr(rename(style = "snake", prefix = "ev.", suffix = ".v2"))]
+///     UserDeleted,
+/// }
+///
+/// assert_eq!(Event::UserCreated.as_ref(), "ev.user_created.v1");
+/// assert_eq!("ev.user_deleted.v2".parse(), Ok(Event::UserDeleted));
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(accept_styles("..."*))]`
+///
+/// Also accepts the variant name in every specified [style](Self#possible-styles) when parsing,
+/// except on variants renamed with `rename("...")`
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(format(style = "snake"))]
+/// #[fromtostr(accept_styles("kebab", "SCREAMING_SNAKE"))]
+/// enum Shape {
+///     RoundedSquare,
+/// }
+///
+/// assert_eq!("rounded_square".parse(), Ok(Shape::RoundedSquare));
+/// assert_eq!("rounded-square".parse(), Ok(Shape::RoundedSquare));
+/// assert_eq!("ROUNDED_SQUARE".parse(), Ok(Shape::RoundedSquare));
+/// assert_eq!(Shape::RoundedSquare.as_ref(), "rounded_square");
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(as_str(vis = "..."?, name = "..."?))]` and `#[fromtostr(from_str_const(vis = "..."?, name = "..."?))]`
+///
+/// Sets the visibility and name of the generated const functions, with the default being `pub`.
+/// Like [`All`]'s `all`, they aren't const on enums with trait bounds.
+/// `from_str_const` only accepts exact matches, ignoring any [parse modes](Self#possible-parse-modes).
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(from_str_const(vis = "pub(crate)", name = "parse"))]
+/// enum Animal {
+///     #[fromtostr(aliases("doggo"))]
+///     Dog,
+///     Cat,
+/// }
+///
+/// const DOG: &str = Animal::Dog.as_str();
+/// const DOGGO: Option<Animal> = Animal::parse("doggo");
+///
+/// assert_eq!(DOG, "Dog");
+/// assert_eq!(DOGGO, Some(Animal::Dog));
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(matcher("..."))]`
+///
+/// Sets how [`FromStr`] looks for the matching string
+///
+///   **Possible Values**
+///
+/// >| Value    | Description                                                                      |
+/// >|----------|----------------------------------------------------------------------------------|
+/// >| `match`  | a single `match` on the string (default)                                         |
+/// >| `length` | dispatches on the length first, then on the byte that splits the variants the most |
+///
+/// `length` is usually faster on enums with lots of variants.
+///
+/// ---
+///
+/// `#[fromtostr(serde(repr = "...", lenient?))]`
+///
+/// Makes [`Serialize`] and [`Deserialize`] use an integer instead of the string, useful on binary formats.
+/// `repr` is either an integer type (`u8` to `u64`, `i8` to `i64`) to cast the discriminant to,
+/// or `index` for the position of the variant as an `u32`.
+/// A discriminant that doesn't fit the integer type is a compile error.
+///
+/// With `lenient`, [`Deserialize`] accepts both the string and the integer,
+/// which needs a self describing format.
+///
+/// ```rust
+/// # #[cfg(feature = "serde")] {
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr)]
+/// #[fromtostr(serde(repr = "u8", lenient))]
+/// enum Priority {
+///     Low = 1,
+///     High = 10,
+/// }
+/// # }
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(error("..."))]`
+///
+/// Sets the error type of [`FromStr`] and [`TryFrom<String>`], with the default being [`ParseEnumError`].
+/// The type needs to implement [`From<ParseEnumError>`](From), `error("()")` keeps the old unit error.
+///
+/// ---
+///
+/// `#[fromtostr(suggest)]`
+///
+/// Adds an function `closest_match`, returning the accepted string closest to the input,
+/// and fills in [`ParseEnumError::suggestion`] when parsing fails.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug)]
+/// #[fromtostr(suggest)]
+/// #[fromtostr(format(style = "lower"))]
+/// enum Level {
+///     Warn,
+///     Error,
+/// }
+///
+/// assert_eq!(Level::closest_match("warnn"), Some("warn"));
+/// assert_eq!("eror".parse::<Level>().unwrap_err().suggestion(), Some("error"));
+/// assert_eq!(Level::closest_match("info"), None);
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(value_enum)]`
+///
+/// Implements clap's [`ValueEnum`][^clap], which needs the enum to implement [`Clone`].
+///
+/// ---
+///
+/// `#[fromtostr(sqlx)]`
+///
+/// Implements sqlx's [`Type`][^sqlx], [`Encode`][^sqlx] and [`Decode`][^sqlx] for sqlite.
+///
+/// ---
+///
+/// `#[fromtostr(placeholder("..."))]`
+///
+/// String of the variants with `skip(display)`.
+/// Without it, nothing needing the string of every variant is implemented,
+/// which is [`AsRef<str>`], [`Into<String>`], [`Display`], `as_str`, string [`Serialize`],
+/// the `as_{name}` functions of `repr` and `display_in`.
+///
+/// ---
+///
+/// `#[fromtostr(crate = "...")]`
+///
+/// Path to this crate used by the generated code, for when it's renamed or re-exported.
+/// Defaults to `::fieldless_enum_tools`. [`All`] and [`Not`] don't need it, since they don't refer to this crate.
+///
+/// ```rust
+/// mod facade {
+///     pub(crate) use fieldless_enum_tools as enums;
+/// }
+///
+/// #[derive(facade::enums::FromToStr)]
+/// #[fromtostr(crate = "facade::enums")]
+/// enum Color {
+///     Red,
+/// }
+/// # fn main() {}
+/// ```
 ///
 /// ---
 ///
-/// `#[fromtostr(format(style = "...", separator = "..."?))]`
+/// `#[fromtostr(repr((name | name(style = "...", separator = "..."?, digits = "..."?, prefix = "..."?, suffix = "..."?))*))]`
+///
+/// Declares named representations, each getting `as_{name}` and `from_{name}` functions.
+/// Variants without an explicit value use the given [style](Self#possible-styles),
+/// or their main string if there's none. `from_{name}` only accepts the exact strings.
+/// Names like `bytes`, whose functions are already generated, are an error unless that function is skipped.
 ///
-/// Format variants using specified [style](Self#possible-styles)
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq)]
+/// #[fromtostr(format(style = "lower"))]
+/// #[fromtostr(repr(code(style = "UPPER"), label))]
+/// enum Day {
+///     #[fromtostr(repr(code = "MON"))]
+///     Monday,
+///     #[fromtostr(repr(code = "TUE", label = "Tuesday"))]
+///     Tuesday,
+/// }
 ///
+/// assert_eq!(Day::Monday.as_ref(), "monday");
+/// assert_eq!(Day::Monday.as_code(), "MON");
+/// assert_eq!(Day::Monday.as_label(), "monday");
+/// assert_eq!(Day::Tuesday.as_label(), "Tuesday");
+/// assert_eq!(Day::from_code("TUE"), Ok(Day::Tuesday));
+/// assert!(Day::from_code("tue").is_err());
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(parse(...*))]`
+///
+/// Makes parsing less strict, using the specified [parse modes](Self#possible-parse-modes).
+/// Values that become ambiguous under these modes are a compile error.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(format(style = "kebab"))]
+/// #[fromtostr(parse(case_insensitive, ignore_separators))]
+/// enum Color {
+///     LightBlue,
+///     DarkRed,
+/// }
+///
+/// assert_eq!("light-blue".parse(), Ok(Color::LightBlue));
+/// assert_eq!("LIGHT_BLUE".parse(), Ok(Color::LightBlue));
+/// assert_eq!("DarkRed".parse(), Ok(Color::DarkRed));
+/// ```
 ///
 /// ## Variant attributes
 ///
-/// `#[fromtostr(aliases("..."*))]`
+/// `#[fromtostr(aliases("..."*, serde = "..."*, fromstr = "..."*))]`
+///
+/// Specifies one (or more aliases) for this variant.
+/// Aliases with `serde = "..."` are only accepted by [`Deserialize`],
+/// and aliases with `fromstr = "..."` by everything but [`Deserialize`].
+///
+/// ---
+///
+/// `#[fromtostr(deprecated_aliases("..."*))]`
 ///
-/// Specifies one (or more aliases) for this variant
+/// Old names that are still accepted everywhere, but left out of error messages and suggestions.
+/// `parse_with_info` reports them as [`MatchKind::Deprecated`], so callers can warn about them.
 ///
 /// ---
 ///
-/// `#[fromtostr(rename("..."))]` or `#[fromtostr(rename(style = "...", separator = "..."?))]`
+/// `#[fromtostr(rename("..."))]` or `#[fromtostr(rename(style = "...", separator = "..."?, digits = "..."?, prefix = "..."?, suffix = "..."?))]`
 ///
 /// Renames this variant with specified string or specified [format style](Self#possible-styles)
 ///
+/// ---
+///
+/// `#[fromtostr(parse(...*))]`
+///
+/// Overrides the [parse modes](Self#possible-parse-modes) for this variant
+///
+/// ---
+///
+/// `#[fromtostr(repr(name = "..."*))]`
+///
+/// Sets this variant's value for the named representations,
+/// which are declared on the enum or just by being used.
+///
+/// ---
+///
+/// `#[fromtostr(skip)]` or `#[fromtostr(skip(parse? display?))]`
+///
+/// Excludes this variant from parsing, displaying or both.
+/// Variants with `skip(parse)` are never produced by parsing, except as the `other` variant,
+/// and variants with `skip(display)` use the enum's `placeholder` as their string.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq)]
+/// #[fromtostr(placeholder("unknown"))]
+/// enum Status {
+///     Active,
+///     #[fromtostr(skip)]
+///     __NonExhaustive,
+/// }
+///
+/// assert!("__NonExhaustive".parse::<Status>().is_err());
+/// assert_eq!(Status::__NonExhaustive.as_ref(), "unknown");
+/// assert!("unknown".parse::<Status>().is_err());
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(locale(locale = "..."*))]`
+///
+/// Translates this variant, adding the functions `display_in` and `from_str_in` and the constant `LOCALES`.
+/// Variants without a translation for a locale, and locales nobody translated to,
+/// use the main string, so `as_ref`, [`Display`] and [`FromStr`] are unchanged.
+/// Locale names are the identifiers as written, like `pt_BR`.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq)]
+/// enum Color {
+///     #[fromtostr(locale(pt = "Vermelho", es = "Rojo"))]
+///     Red,
+///     #[fromtostr(locale(pt = "Azul"))]
+///     Blue,
+/// }
+///
+/// assert_eq!(Color::Red.display_in("pt"), "Vermelho");
+/// assert_eq!(Color::Blue.display_in("es"), "Blue");
+/// assert_eq!(Color::Red.display_in("en"), "Red");
+/// assert_eq!(Color::from_str_in("pt", "Azul"), Ok(Color::Blue));
+/// assert!(Color::from_str_in("pt", "Blue").is_err());
+/// assert_eq!(Color::LOCALES, ["pt", "es"]);
+/// ```
+///
+/// ---
+///
+/// `#[fromtostr(other)]`
+///
+/// Parses every unknown string into this variant instead of failing, like `#[serde(other)]`.
+/// Applies to [`FromStr`], [`TryFrom<String>`], `from_bytes`, `from_str_in` and [`Deserialize`],
+/// including invalid utf-8 and unknown `serde(repr = "...")` integers,
+/// but not to `from_str_const` and `parse_prefix`, which only match exact strings.
+/// Only one variant can have it.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq)]
+/// enum Status {
+///     Active,
+///     #[fromtostr(other)]
+///     Unknown,
+/// }
+///
+/// assert_eq!("Active".parse(), Ok(Status::Active));
+/// assert_eq!("Suspended".parse(), Ok(Status::Unknown));
+/// // the original string is lost
+/// assert_eq!(Status::Unknown.as_ref(), "Unknown");
+/// ```
+///
+/// # Possible Parse Modes
+///
+/// >| Mode                | Description                                      | Note                               |
+/// >|---------------------|--------------------------------------------------|------------------------------------|
+/// >| `exact`             | only accept the exact strings (default)          | can't be combined with other modes |
+/// >| `case_insensitive`  | `TwoWords` also accepts `twowords` and `TWOWORDS` |                                    |
+/// >| `ignore_separators` | `TwoWords` also accepts `Two_Words`, `Two-Words` and `Two Words` | separators are `_`, `-` and ` ` |
+///
 /// # Possible Styles
 ///
 /// >| Style Name        | Description                                           | Example               | Note                                                    |
@@ -169,32 +579,97 @@ pub use fieldless_enum_tools_impl::Not;
 /// >| `Pascal`          | to pascal case                                        | `TwoWords`            |                                                         |
 /// >| `Pascal_Snake`    | to pascal snake case                                  | `Two_Words`           |                                                         |
 /// >| `Train`           | to train case                                         | `Two-Words`           |                                                         |
-/// >| `delimited`       | delimits every word with separator                    | `Two{separator}Words` | needs to specify a separator value                      |
+/// >| `delimited`       | capitalizes, then delimits every word with separator  | `Two{separator}Words` | needs to specify a separator value                      |
 /// >| `delimitedlower`  | delimits every word with separator, then to lowercase | `two{separator}words` | needs to specify a separator value                      |
 /// >| `DELIMITEDUPPER`  | delimits every word with separator, then to uppercase | `TWO{SEPARATOR}WORDS` | needs to specify a separator value                      |
-
+///
+/// Every style but `none` splits the variant name into words first.
+/// A word starts at an uppercase letter after a non uppercase one (`Two` `Words`),
+/// at the last letter of an acronym (`HTTP` `Request`) and after underscores, which are dropped.
+/// Underscores in names are separators, so styles without one remove them,
+/// and `Two_Words` is `twowords` in `lower` and `twoWords` in `camel`, where it used to keep them.
+/// Digits stay in the word before them (`Http2` `Error`),
+/// unless `digits = "separate"` makes them a word on their own (`Http` `2` `Error`).
+/// `accept_styles` splits words and adds the prefix and suffix like `format`,
+/// or like `rename` on variants with their own style.
+///
+/// ```rust
+/// use fieldless_enum_tools::FromToStr;
+///
+/// #[derive(FromToStr, Debug, PartialEq, Eq)]
+/// #[fromtostr(format(style = "snake"))]
+/// enum Protocol {
+///     Http2Error,
+///     HTTPRequest,
+///     #[fromtostr(rename(style = "kebab", digits = "separate"))]
+///     Ipv4Addr,
+/// }
+///
+/// assert_eq!(Protocol::Http2Error.as_ref(), "http2_error");
+/// assert_eq!(Protocol::HTTPRequest.as_ref(), "http_request");
+/// assert_eq!(Protocol::Ipv4Addr.as_ref(), "ipv-4-addr");
+/// ```
 ///
 /// [^alloc]: if crate feature `std` or `alloc` avaliable.
 ///
+/// [^std]: if crate feature `std` avaliable.
+///
 /// [^serde]: if crate feature `serde` avaliable.
 ///
+/// [^clap]: if the crate deriving it depends on `clap` 4, which isn't a crate feature.
+/// Uses the variant doc comments as help.
+///
+/// [^schemars]: if crate feature `schemars` avaliable.
+/// Describes the strings (or integers, with `serde(repr = "...")`) that [`Serialize`] produces,
+/// with doc comments as descriptions, and accepts any string if there's an `other` variant.
+/// That includes variants with `skip(parse)`, even though [`Deserialize`] rejects them.
+///
+/// [^rusqlite]: if crate features `rusqlite` and `std` avaliable.
+/// Variants are stored as `TEXT`, and read back like [`FromStr`].
+///
+/// [^sqlx]: if the crate deriving it depends on `sqlx` 0.8 with its `sqlite` or `sqlite-unbundled`
+/// feature, which isn't a crate feature, and crate feature `std` avaliable.
+/// Stored like with `rusqlite`.
+///
 /// [`Display`]: `core::fmt::Display`
 /// [`TryFrom<String>`]: `core::convert::TryFrom`
+/// [`TryFrom<&OsStr>`]: `core::convert::TryFrom`
 /// [`FromStr`]: `core::str::FromStr`
 /// [`Serialize`]: https://serde.rs/
 /// [`Deserialize`]: https://serde.rs/
+/// [`ValueEnum`]: https://docs.rs/clap/4/clap/trait.ValueEnum.html
+/// [`JsonSchema`]: https://docs.rs/schemars/1/schemars/trait.JsonSchema.html
+/// [`ToSql`]: https://docs.rs/rusqlite/0.32/rusqlite/types/trait.ToSql.html
+/// [`FromSql`]: https://docs.rs/rusqlite/0.32/rusqlite/types/trait.FromSql.html
+/// [`Type`]: https://docs.rs/sqlx/0.8/sqlx/trait.Type.html
+/// [`Encode`]: https://docs.rs/sqlx/0.8/sqlx/trait.Encode.html
+/// [`Decode`]: https://docs.rs/sqlx/0.8/sqlx/trait.Decode.html
 pub use fieldless_enum_tools_impl::FromToStr;
 
 #[cfg(not(doc))]
 pub mod __internal {
+    pub use crate::normalize::{eq_bytes, eq_normalized};
+    pub use crate::suggest::closest_match;
+
     #[cfg(all(feature = "alloc", not(feature = "std")))]
     pub use alloc::string::String;
     #[cfg(feature = "std")]
     pub use std::string::String;
 
+    #[cfg(feature = "std")]
+    pub use std::ffi::OsStr;
+
     #[cfg(feature = "serde")]
     pub use serde;
 
+    #[cfg(feature = "schemars")]
+    pub use alloc::borrow::Cow;
+    #[cfg(feature = "schemars")]
+    pub use schemars;
+
+    #[cfg(feature = "rusqlite")]
+    pub use rusqlite;
+
     #[cfg(not(feature = "serde"))]
     #[macro_export]
     macro_rules! if_serde_enabled {
@@ -207,6 +682,30 @@ pub mod __internal {
         ($($t:tt)*) => { $($t)* };
     }
 
+    #[cfg(not(feature = "schemars"))]
+    #[macro_export]
+    macro_rules! if_schemars_enabled {
+        ($($t:tt)*) => {};
+    }
+
+    #[cfg(feature = "schemars")]
+    #[macro_export]
+    macro_rules! if_schemars_enabled {
+        ($($t:tt)*) => { $($t)* };
+    }
+
+    #[cfg(not(feature = "rusqlite"))]
+    #[macro_export]
+    macro_rules! if_rusqlite_enabled {
+        ($($t:tt)*) => {};
+    }
+
+    #[cfg(feature = "rusqlite")]
+    #[macro_export]
+    macro_rules! if_rusqlite_enabled {
+        ($($t:tt)*) => { $($t)* };
+    }
+
     #[cfg(not(any(feature = "alloc", feature = "std")))]
     #[macro_export]
     macro_rules! if_alloc_enabled {
@@ -0,0 +1,35 @@
+/// Which kind of string a value was parsed from, returned by `parse_with_info`.
+///
+/// ```rust
+/// use fieldless_enum_tools::{FromToStr, MatchKind};
+///
+/// #[derive(FromToStr, Debug, PartialEq)]
+/// enum Color {
+///     #[fromtostr(aliases("grey"))]
+///     #[fromtostr(deprecated_aliases("gray"))]
+///     Gray,
+/// }
+///
+/// assert_eq!(Color::parse_with_info("Gray"), Ok((Color::Gray, MatchKind::Primary)));
+/// assert_eq!(Color::parse_with_info("grey"), Ok((Color::Gray, MatchKind::Alias)));
+/// assert_eq!(Color::parse_with_info("gray"), Ok((Color::Gray, MatchKind::Deprecated)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MatchKind {
+    /// The formatted variant name, which is also what gets displayed.
+    Primary,
+    /// An alias, or the variant name in one of `accept_styles`.
+    Alias,
+    /// An alias from `deprecated_aliases`.
+    Deprecated,
+    /// Nothing matched, so the value is the `#[fromtostr(other)]` variant.
+    Other,
+}
+
+impl MatchKind {
+    /// Returns `true` if the input should be replaced by the primary string.
+    #[inline]
+    pub const fn is_deprecated(self) -> bool {
+        matches!(self, Self::Deprecated)
+    }
+}
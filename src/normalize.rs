@@ -0,0 +1,46 @@
+/// Checks if `input` is equal to an already normalized string,
+/// normalizing `input` on the fly so it doesn't need to allocate.
+///
+/// Should match `ParseMode::normalize` on the impl crate.
+pub fn eq_normalized(
+    input: &str,
+    normalized: &str,
+    case_insensitive: bool,
+    ignore_separators: bool,
+) -> bool {
+    let mut expected = normalized.chars();
+
+    for ch in input.chars() {
+        if ignore_separators && matches!(ch, '_' | '-' | ' ') {
+            continue;
+        }
+
+        if case_insensitive {
+            for ch in ch.to_lowercase() {
+                if expected.next() != Some(ch) {
+                    return false;
+                }
+            }
+        } else if expected.next() != Some(ch) {
+            return false;
+        }
+    }
+
+    expected.next().is_none()
+}
+
+/// Byte-wise equality usable in const contexts.
+pub const fn eq_bytes(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
@@ -0,0 +1,55 @@
+/// Longest string (in chars) that [`closest_match`] will consider,
+/// since the distance is calculated without allocating.
+const MAX_LEN: usize = 64;
+
+/// Returns the candidate with the smallest edit distance to `input`,
+/// if it's close enough to be a plausible typo.
+///
+/// Characters that only differ by ascii case are considered equal.
+pub fn closest_match(input: &str, candidates: &[&'static str]) -> Option<&'static str> {
+    let mut best: Option<(usize, &'static str)> = None;
+
+    for &candidate in candidates {
+        let distance = match distance(input, candidate) {
+            Some(d) => d,
+            None => continue,
+        };
+
+        // allow roughly one typo every three characters
+        let max = core::cmp::max(1, candidate.chars().count() / 3);
+        if distance <= max && best.map_or(true, |(d, _)| distance < d) {
+            best = Some((distance, candidate));
+        }
+    }
+
+    best.map(|(_, s)| s)
+}
+
+/// Levenshtein distance between `a` and `b`, or `None` if `b` is longer than [`MAX_LEN`].
+fn distance(a: &str, b: &str) -> Option<usize> {
+    let b_len = b.chars().count();
+    if b_len > MAX_LEN {
+        return None;
+    }
+
+    // only keep the previous row of the matrix around
+    let mut row = [0; MAX_LEN + 1];
+    for (i, cell) in row.iter_mut().enumerate().take(b_len + 1) {
+        *cell = i;
+    }
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+
+        for (j, cb) in b.chars().enumerate() {
+            let cost = if ca.eq_ignore_ascii_case(&cb) { 0 } else { 1 };
+            let above = row[j + 1];
+
+            row[j + 1] = core::cmp::min(core::cmp::min(above, row[j]) + 1, diagonal + cost);
+            diagonal = above;
+        }
+    }
+
+    Some(row[b_len])
+}
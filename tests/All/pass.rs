@@ -7,9 +7,35 @@ enum MyCoolEnum {
     C,
     D,
 }
+
+// fieldless enums can't use type or lifetime parameters, but const ones are fine
+#[derive(Debug, All, PartialEq, Eq)]
+enum Generic<const N: usize>
+where
+    [u8; N]: Default,
+{
+    A,
+    B,
+}
+
+#[derive(Debug, All, PartialEq, Eq)]
+enum Cfgd {
+    A,
+    // `any()` is always false, `all()` always true
+    #[cfg(any())]
+    B,
+    #[cfg(all())]
+    C,
+    #[cfg(all())]
+    #[cfg(any())]
+    D,
+}
+
 fn main() {
     assert_eq!(
         MyCoolEnum::all(),
         [MyCoolEnum::A, MyCoolEnum::B, MyCoolEnum::C, MyCoolEnum::D]
-    )
+    );
+    assert_eq!(Generic::<4>::all(), [Generic::A, Generic::B]);
+    assert_eq!(Cfgd::all(), [Cfgd::A, Cfgd::C]);
 }
@@ -0,0 +1,95 @@
+use fieldless_enum_tools::FromToStr;
+use schemars::{JsonSchema, SchemaGenerator};
+use serde_json::{json, Value};
+
+/// Log verbosity
+#[derive(Debug, FromToStr)]
+#[fromtostr(format(style = "lower"))]
+enum Level {
+    #[fromtostr(aliases("warning"))]
+    Warn,
+    Error,
+    #[cfg(any())]
+    Trace,
+    #[fromtostr(skip(parse))]
+    Internal,
+}
+
+#[derive(Debug, FromToStr)]
+#[fromtostr(format(style = "kebab"))]
+enum Documented {
+    /// Fast, but
+    /// less accurate
+    QuickScan,
+    FullScan,
+}
+
+#[derive(Debug, FromToStr)]
+#[fromtostr(serde(repr = "u8"))]
+enum Discriminant {
+    A = 1,
+    B = 5,
+}
+
+#[derive(Debug, FromToStr)]
+enum Fallback {
+    Known,
+    #[fromtostr(other)]
+    Unknown,
+}
+
+#[derive(Debug, FromToStr)]
+enum Generic<const N: usize>
+where
+    [(); N]: Sized,
+{
+    A,
+    B,
+}
+
+#[derive(Debug, FromToStr)]
+#[fromtostr(skip(JsonSchema))]
+enum Skipped {
+    A,
+}
+
+fn schema<T: JsonSchema>() -> Value {
+    T::json_schema(&mut SchemaGenerator::default()).to_value()
+}
+
+pub fn tests() {
+    assert_eq!(
+        schema::<Level>(),
+        json!({
+            "type": "string",
+            "enum": ["warn", "error", "internal"],
+            "description": "Log verbosity",
+        })
+    );
+    assert_eq!(
+        schema::<Documented>(),
+        json!({
+            "type": "string",
+            "oneOf": [
+                { "const": "quick-scan", "description": "Fast, but\nless accurate" },
+                { "const": "full-scan" },
+            ],
+        })
+    );
+    assert_eq!(
+        schema::<Discriminant>(),
+        json!({ "type": "integer", "enum": [1, 5] })
+    );
+    assert_eq!(schema::<Fallback>(), json!({ "type": "string" }));
+    assert_eq!(
+        schema::<Generic<1>>(),
+        json!({ "type": "string", "enum": ["A", "B"] })
+    );
+
+    // can't be deserialized, but it's still serialized
+    assert_eq!(Level::Internal.as_ref(), "internal");
+    assert_eq!(Level::schema_name(), "Level");
+    assert!(Level::schema_id().ends_with("::Level"));
+
+    let _ = Skipped::A;
+}
@@ -0,0 +1,232 @@
+use fieldless_enum_tools::FromToStr;
+use serde::Deserialize as _;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{from_str, to_string};
+
+#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+struct Config {
+    str: Str,
+    index: Index,
+    list: Vec<Str>,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "snake"))]
+enum Str {
+    #[fromtostr(aliases("one"))]
+    VariantOne,
+    VariantTwo,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum Scoped {
+    #[fromtostr(aliases("both", serde = "old_name", fromstr = "cli"))]
+    Variant,
+    #[fromtostr(aliases(serde = "cli"))]
+    Other,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "u8"))]
+enum Discriminant {
+    A = 1,
+    B = 5,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "i8", lenient))]
+enum Lenient {
+    Negative = -1,
+    Zero = 0,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "index"))]
+enum Index {
+    A = 10,
+    B = 20,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum Fallback {
+    Known,
+    #[fromtostr(other)]
+    Unknown,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "u8"))]
+enum ReprFallback {
+    Known = 1,
+    #[fromtostr(other)]
+    Unknown = 0,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum ScopedFallback {
+    #[fromtostr(aliases(fromstr = "cli"))]
+    Known,
+    #[fromtostr(other)]
+    Unknown,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum Generic<const N: usize>
+where
+    [u8; N]: Default,
+{
+    A,
+    B,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "index"))]
+enum GenericIndex<const N: usize>
+where
+    [u8; N]: Default,
+{
+    A,
+    B,
+}
+
+mod facade {
+    pub use fieldless_enum_tools as enums;
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(crate = "self::facade::enums")]
+enum Reexported {
+    A,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(serde(repr = "u8", lenient))]
+enum Cfgd {
+    A = 1,
+    #[cfg(any())]
+    B = 2,
+}
+
+// can't be serialized as a string, but can still be deserialized
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum NoDisplay {
+    A,
+    #[fromtostr(skip(display))]
+    B,
+}
+
+pub fn tests() {
+    assert_eq!(to_string(&Str::VariantOne).unwrap(), r#""variant_one""#);
+    assert_eq!(
+        from_str::<Str>(r#""variant_two""#).unwrap(),
+        Str::VariantTwo
+    );
+    assert_eq!(from_str::<Str>(r#""one""#).unwrap(), Str::VariantOne);
+    assert_eq!(from_str::<Fallback>(r#""Known""#).unwrap(), Fallback::Known);
+    assert_eq!(to_string(&Generic::<4>::A).unwrap(), r#""A""#);
+    assert_eq!(to_string(&Cfgd::A).unwrap(), "1");
+    assert_eq!(from_str::<NoDisplay>(r#""B""#).unwrap(), NoDisplay::B);
+    assert_eq!(from_str::<Cfgd>(r#""A""#).unwrap(), Cfgd::A);
+    assert!(from_str::<Cfgd>("2").is_err());
+    assert!(from_str::<Cfgd>(r#""B""#).is_err());
+    assert_eq!(from_str::<Reexported>(r#""A""#).unwrap(), Reexported::A);
+    assert_eq!(from_str::<Generic<4>>(r#""B""#).unwrap(), Generic::B);
+    assert_eq!(from_str::<GenericIndex<4>>("1").unwrap(), GenericIndex::B);
+    assert_eq!(from_str::<Fallback>(r#""new""#).unwrap(), Fallback::Unknown);
+    assert_eq!(from_str::<ReprFallback>("1").unwrap(), ReprFallback::Known);
+    assert_eq!(
+        from_str::<ReprFallback>("7").unwrap(),
+        ReprFallback::Unknown
+    );
+    assert_eq!(
+        from_str::<ReprFallback>("-1").unwrap(),
+        ReprFallback::Unknown
+    );
+    assert_eq!(
+        from_str::<ReprFallback>("300").unwrap(),
+        ReprFallback::Unknown
+    );
+    assert_eq!(
+        from_str::<ScopedFallback>(r#""cli""#).unwrap(),
+        ScopedFallback::Unknown
+    );
+    assert_eq!(
+        from_str::<Str>(r#""three""#).unwrap_err().to_string(),
+        "unknown variant `three`, expected one of `variant_one`, `one`, `variant_two` at line 1 column 7"
+    );
+    assert_eq!(
+        from_str::<Str>("1").unwrap_err().to_string(),
+        "invalid type: integer `1`, expected one of `variant_one`, `one`, `variant_two` at line 1 column 1"
+    );
+    // borrowed from the input
+    assert_eq!(
+        Str::deserialize(&mut serde_json::Deserializer::from_str(r#""one""#)).unwrap(),
+        Str::VariantOne
+    );
+    // owned because of the escape
+    assert_eq!(from_str::<Str>(r#""\u006fne""#).unwrap(), Str::VariantOne);
+
+    assert_eq!(from_str::<Scoped>(r#""both""#).unwrap(), Scoped::Variant);
+    assert_eq!(
+        from_str::<Scoped>(r#""old_name""#).unwrap(),
+        Scoped::Variant
+    );
+    assert_eq!(from_str::<Scoped>(r#""cli""#).unwrap(), Scoped::Other);
+    assert_eq!("both".parse(), Ok(Scoped::Variant));
+    assert_eq!("cli".parse(), Ok(Scoped::Variant));
+    assert!("old_name".parse::<Scoped>().is_err());
+    assert_eq!(
+        from_str::<Scoped>(r#""x""#).unwrap_err().to_string(),
+        "unknown variant `x`, expected one of `Variant`, `both`, `old_name`, `Other`, `cli` at line 1 column 3"
+    );
+
+    assert_eq!(to_string(&Discriminant::B).unwrap(), "5");
+    assert_eq!(from_str::<Discriminant>("1").unwrap(), Discriminant::A);
+    assert_eq!(
+        from_str::<Discriminant>("2").unwrap_err().to_string(),
+        "invalid value: integer `2`, expected an u8 discriminant of Discriminant at line 1 column 1"
+    );
+    assert!(from_str::<Discriminant>("256").is_err());
+    assert!(from_str::<Discriminant>(r#""A""#).is_err());
+
+    assert_eq!(to_string(&Lenient::Negative).unwrap(), "-1");
+    assert_eq!(from_str::<Lenient>("-1").unwrap(), Lenient::Negative);
+    assert_eq!(from_str::<Lenient>(r#""Zero""#).unwrap(), Lenient::Zero);
+    assert_eq!(
+        from_str::<Lenient>("1").unwrap_err().to_string(),
+        "invalid value: integer `1`, expected `Negative` or `Zero` or an i8 discriminant of Lenient at line 1 column 1"
+    );
+
+    assert_eq!(to_string(&Index::B).unwrap(), "1");
+    assert_eq!(from_str::<Index>("0").unwrap(), Index::A);
+    assert!(from_str::<Index>("10").is_err());
+
+    let config = Config {
+        str: Str::VariantTwo,
+        index: Index::B,
+        list: vec![Str::VariantOne, Str::VariantTwo],
+    };
+    let json = to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        r#"{"str":"variant_two","index":1,"list":["variant_one","variant_two"]}"#
+    );
+    assert_eq!(from_str::<Config>(&json).unwrap(), config);
+
+    let toml = toml::to_string(&config).unwrap();
+    assert_eq!(
+        toml,
+        "str = \"variant_two\"\nindex = 1\nlist = [\"variant_one\", \"variant_two\"]\n"
+    );
+    assert_eq!(toml::from_str::<Config>(&toml).unwrap(), config);
+    let err = toml::from_str::<Config>("str = \"three\"\nindex = 0\nlist = []")
+        .unwrap_err()
+        .to_string();
+    assert!(
+        err.contains(
+            "unknown variant `three`, expected one of `variant_one`, `one`, `variant_two`"
+        ),
+        "{}",
+        err
+    );
+}
@@ -0,0 +1,19 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(parse(case_insensitive))]
+enum Enum {
+    VariantOne,
+    #[fromtostr(rename("variantone"))]
+    VariantTwo,
+}
+
+#[derive(FromToStr)]
+enum MixedModes {
+    #[fromtostr(parse(ignore_separators))]
+    VariantOne,
+    #[fromtostr(rename("Variant_One"))]
+    VariantTwo,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+enum Enum {
+    VariantOne,
+    #[fromtostr(skip(display))]
+    VariantTwo,
+}
+
+fn main() {
+    // there's no string for VariantTwo, so there's no AsRef<str> either
+    let _ = <Enum as AsRef<str>>::as_ref(&Enum::VariantOne);
+}
@@ -0,0 +1,11 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(accept_styles("snake"))]
+enum Enum {
+    VariantOne,
+    #[fromtostr(aliases("variant_one"))]
+    VariantTwo,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+enum Enum {
+    #[fromtostr(deprecated_aliases("old"))]
+    VariantOne,
+    #[fromtostr(aliases("old"))]
+    VariantTwo,
+}
+
+fn main() {}
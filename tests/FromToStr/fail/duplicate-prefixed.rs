@@ -0,0 +1,12 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(format(style = "snake", prefix = "ev.", suffix = ".v1"))]
+enum Event {
+    UserCreated,
+    // formatted as "ev.user_created.v1" too
+    #[fromtostr(rename(style = "snake", prefix = "ev.user_", suffix = ".v1"))]
+    Created,
+}
+
+fn main() {}
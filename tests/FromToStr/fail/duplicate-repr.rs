@@ -0,0 +1,12 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(repr(code(style = "UPPER")))]
+enum Enum {
+    #[fromtostr(repr(code = "ONE"))]
+    VariantOne,
+    // the main strings differ, but both codes are ONE
+    One,
+}
+
+fn main() {}
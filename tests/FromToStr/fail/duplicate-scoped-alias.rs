@@ -0,0 +1,16 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+enum Enum {
+    // fine, they're on different scopes
+    #[fromtostr(aliases(serde = "one"))]
+    VariantOne,
+    #[fromtostr(aliases(fromstr = "one"))]
+    VariantTwo,
+    #[fromtostr(aliases(serde = "two"))]
+    VariantThree,
+    #[fromtostr(aliases("two"))]
+    VariantFour,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+enum Enum {
+    #[fromtostr(other)]
+    VariantOne,
+    VariantTwo,
+    #[fromtostr(other)]
+    VariantThree,
+}
+
+fn main() {}
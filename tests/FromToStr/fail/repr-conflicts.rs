@@ -0,0 +1,17 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(repr(bytes))]
+enum Declared {
+    A,
+}
+
+// `as_str` is only free once it's skipped
+#[derive(FromToStr)]
+#[fromtostr(skip(AsStr))]
+enum Variant {
+    #[fromtostr(repr(str = "a", str_const = "b"))]
+    A,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use fieldless_enum_tools::FromToStr;
+
+#[derive(FromToStr)]
+#[fromtostr(serde(repr = "u8"))]
+enum Enum {
+    // would serialize as 255, like `B`
+    A = -1,
+    B = 255,
+}
+
+fn main() {}
@@ -1,6 +1,17 @@
+// integrations need crate features, which trybuild doesn't pass along
+#[cfg(feature = "schemars")]
+mod Schemars;
+#[cfg(feature = "serde")]
+mod Serde;
+
 pub fn tests() {
     let t = trybuild::TestCases::new();
 
     t.pass("tests/FromToStr/pass.rs");
     t.compile_fail("tests/FromToStr/fail/*.rs");
+
+    #[cfg(feature = "serde")]
+    Serde::tests();
+    #[cfg(feature = "schemars")]
+    Schemars::tests();
 }
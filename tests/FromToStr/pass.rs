@@ -1,4 +1,6 @@
-use fieldless_enum_tools::{All, FromToStr};
+use fieldless_enum_tools::{All, FromToStr, MatchKind, ParseEnumError};
+use std::convert::TryFrom;
+use std::ffi::OsStr;
 
 #[derive(Debug, FromToStr, All, PartialEq, Eq)]
 #[fromtostr(format(style = "delimitedlower", separator = "❤️.❤️"))]
@@ -8,6 +10,238 @@ enum CoolEnum {
     VariantNumberTwo,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+struct MyError(String);
+
+impl From<ParseEnumError> for MyError {
+    fn from(e: ParseEnumError) -> Self {
+        Self(e.input().to_owned())
+    }
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(error("MyError"))]
+enum CustomError {
+    A,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(error("()"))]
+enum UnitError {
+    A,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(suggest)]
+#[fromtostr(format(style = "snake"))]
+enum Level {
+    Warn,
+    #[fromtostr(aliases("err"))]
+    Error,
+    VeryVerbose,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "kebab"))]
+#[fromtostr(parse(case_insensitive, ignore_separators))]
+enum Lenient {
+    TwoWords,
+    #[fromtostr(parse(case_insensitive))]
+    #[fromtostr(aliases("Żółw"))]
+    Turtle,
+    #[fromtostr(parse(exact))]
+    Strict,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(accept_styles("snake", "lower", "kebab", "SCREAMING_SNAKE"))]
+enum Styled {
+    TwoWords,
+    // lower and snake are the same here
+    One,
+    #[fromtostr(rename("renamed"))]
+    Renamed,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "snake", digits = "separate"))]
+#[fromtostr(accept_styles("kebab"))]
+#[fromtostr(repr(attached(style = "kebab")))]
+enum Protocol {
+    Http2Error,
+    HTTPRequest,
+    Ipv4Addr,
+    #[fromtostr(rename(style = "SCREAMING_SNAKE", digits = "attach"))]
+    Utf8Text,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "snake", prefix = "ev.", suffix = ".v1"))]
+#[fromtostr(accept_styles("kebab"))]
+#[fromtostr(repr(topic(style = "kebab", prefix = "events/")))]
+enum Event {
+    UserCreated,
+    #[fromtostr(rename(style = "snake", prefix = "ev.", suffix = ".v2"))]
+    UserDeleted,
+    #[fromtostr(rename("legacy"))]
+    Legacy,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(as_str(vis = "pub(crate)", name = "name"))]
+#[fromtostr(skip(FromStrConst))]
+enum Named {
+    A,
+}
+
+impl Named {
+    // would conflict if FromStrConst wasn't skipped
+    #[allow(dead_code)]
+    fn from_str_const() {}
+}
+
+const VARIANT_ONE: &str = CoolEnum::VariantNumberOne.as_str();
+const PARSED: [Option<CoolEnum>; 3] = [
+    CoolEnum::from_str_const("VariantNumberOne"),
+    CoolEnum::from_str_const("variant❤️.❤️number❤️.❤️two"),
+    CoolEnum::from_str_const("variantnumberone"),
+];
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(matcher("length"))]
+#[fromtostr(format(style = "UPPER"))]
+enum Country {
+    #[fromtostr(aliases("Brasil"))]
+    Br,
+    Pt,
+    Ar,
+    Au,
+    At,
+    Be,
+    Bo,
+    #[fromtostr(rename("Portugal"))]
+    Portugal,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum Scoped {
+    #[fromtostr(aliases("both", serde = "old_name", fromstr = "cli"))]
+    Variant,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(accept_styles("snake"))]
+enum ScopedStyled {
+    // the style still counts outside of serde
+    #[fromtostr(aliases(serde = "two_words"))]
+    TwoWords,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq, Clone, Copy)]
+#[fromtostr(format(style = "snake"))]
+#[fromtostr(parse(case_insensitive))]
+#[fromtostr(suggest)]
+enum Renamed {
+    #[fromtostr(aliases("newest"))]
+    #[fromtostr(deprecated_aliases("old_name", "older_name"))]
+    NewName,
+    Other,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(error("MyError"))]
+enum Fallback {
+    #[fromtostr(locale(pt = "Conhecido"))]
+    Known,
+    #[fromtostr(other)]
+    Unknown,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "lower"))]
+#[fromtostr(repr(code(style = "UPPER"), label))]
+#[fromtostr(error("MyError"))]
+enum Day {
+    #[fromtostr(repr(code = "MON", label = "Monday"))]
+    Monday,
+    #[fromtostr(repr(abbr = "tu"))]
+    Tuesday,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "lower"))]
+enum Translated {
+    #[fromtostr(locale(en = "Red", pt = "Vermelho", pt_BR = "Vermelho"))]
+    Red,
+    #[fromtostr(locale(pt = "Verde"))]
+    Green,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "lower"))]
+#[fromtostr(suggest)]
+#[fromtostr(repr(code(style = "UPPER")))]
+enum Generic<const N: usize>
+where
+    [u8; N]: Default,
+{
+    #[fromtostr(locale(pt = "um"))]
+    One,
+    Two,
+}
+
+mod facade {
+    pub use fieldless_enum_tools as enums;
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(crate = "crate::facade::enums")]
+#[fromtostr(suggest)]
+#[fromtostr(parse(case_insensitive))]
+#[fromtostr(repr(code))]
+enum Reexported {
+    #[fromtostr(locale(pt = "Um"))]
+    #[fromtostr(deprecated_aliases("uno"))]
+    One,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "lower"))]
+#[fromtostr(parse(case_insensitive))]
+#[fromtostr(matcher("length"))]
+#[fromtostr(suggest)]
+enum Cfgd {
+    #[fromtostr(repr(code = "E"))]
+    #[fromtostr(locale(pt = "Ligado"))]
+    Enabled,
+    #[cfg(any())]
+    #[fromtostr(aliases("gone"))]
+    #[fromtostr(repr(code = "D"))]
+    #[fromtostr(locale(pt = "Desligado"))]
+    Disabled,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(placeholder("<hidden>"))]
+#[fromtostr(repr(code))]
+enum Sentinel {
+    Visible,
+    #[fromtostr(skip(parse))]
+    Internal,
+    #[fromtostr(skip(display))]
+    #[fromtostr(aliases("secret"))]
+    Hidden,
+    #[fromtostr(skip)]
+    __NonExhaustive,
+}
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+enum NoDisplay {
+    A,
+    #[fromtostr(skip(display))]
+    B,
+}
+
 fn main() {
     assert_eq!("variant_number_one".parse(), Ok(CoolEnum::VariantNumberOne));
     assert_eq!("VariantNumberOne".parse(), Ok(CoolEnum::VariantNumberOne));
@@ -15,4 +249,262 @@ fn main() {
         "variant❤️.❤️number❤️.❤️one".parse(),
         Ok(CoolEnum::VariantNumberOne)
     );
+
+    let err = "variant_number_three".parse::<CoolEnum>().unwrap_err();
+    assert_eq!(err.enum_name(), "CoolEnum");
+    assert_eq!(err.input(), "variant_number_three");
+    assert_eq!(
+        CoolEnum::try_from(String::from("variant_number_three")),
+        Err(err)
+    );
+
+    assert_eq!("B".parse::<CustomError>(), Err(MyError("B".to_owned())));
+    assert_eq!("B".parse::<UnitError>(), Err(()));
+
+    assert_eq!(Level::closest_match("warnn"), Some("warn"));
+    assert_eq!(Level::closest_match("WARN"), Some("warn"));
+    assert_eq!(Level::closest_match("very_verbos"), Some("very_verbose"));
+    assert_eq!(Level::closest_match("info"), None);
+    let err = "erro".parse::<Level>().unwrap_err();
+    assert_eq!(err.suggestion(), Some("error"));
+    assert_eq!(
+        err.to_string(),
+        r#"invalid value "erro" for Level, did you mean "error"?"#
+    );
+    assert_eq!(
+        "Warnn".parse::<CoolEnum>().map_err(|e| e.suggestion()),
+        Err(None)
+    );
+
+    assert_eq!(
+        Renamed::parse_with_info("new_name"),
+        Ok((Renamed::NewName, MatchKind::Primary))
+    );
+    assert_eq!(
+        Renamed::parse_with_info("Newest"),
+        Ok((Renamed::NewName, MatchKind::Alias))
+    );
+    assert_eq!(
+        Renamed::parse_with_info("OLD_NAME"),
+        Ok((Renamed::NewName, MatchKind::Deprecated))
+    );
+    assert!(MatchKind::Deprecated.is_deprecated());
+    assert_eq!("older_name".parse(), Ok(Renamed::NewName));
+    assert_eq!(Renamed::parse_prefix("old_name!"), Some((Renamed::NewName, "!")));
+    assert_eq!(Renamed::closest_match("old_nam"), None);
+    assert!(Renamed::parse_with_info("unknown").is_err());
+
+    assert_eq!("Known".parse(), Ok(Fallback::Known));
+    assert_eq!("Unknown".parse(), Ok(Fallback::Unknown));
+    assert_eq!("new".parse(), Ok(Fallback::Unknown));
+    assert_eq!(Fallback::try_from(String::new()), Ok(Fallback::Unknown));
+    assert_eq!(
+        Fallback::parse_with_info("new"),
+        Ok((Fallback::Unknown, MatchKind::Other))
+    );
+    assert_eq!(
+        Fallback::parse_with_info("Unknown"),
+        Ok((Fallback::Unknown, MatchKind::Primary))
+    );
+    assert_eq!(Fallback::from_str_const("new"), None);
+    assert_eq!(Fallback::from_str_in("pt", "Conhecido"), Ok(Fallback::Known));
+    assert_eq!(Fallback::from_str_in("pt", "novo"), Ok(Fallback::Unknown));
+    assert_eq!(Fallback::from_str_in("en", "new"), Ok(Fallback::Unknown));
+    // invalid utf-8 is unknown too
+    assert_eq!(Fallback::from_bytes(b"\xff"), Ok(Fallback::Unknown));
+    assert_eq!(Fallback::try_from(&b"\xff"[..]), Ok(Fallback::Unknown));
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+        assert_eq!(
+            Fallback::try_from(OsStr::from_bytes(b"\xff")),
+            Ok(Fallback::Unknown)
+        );
+    }
+
+    assert_eq!(Day::Monday.as_str(), "monday");
+    assert_eq!(Day::Monday.as_code(), "MON");
+    assert_eq!(Day::Tuesday.as_code(), "TUESDAY");
+    assert_eq!(Day::Monday.as_label(), "Monday");
+    assert_eq!(Day::Tuesday.as_label(), "tuesday");
+    assert_eq!(Day::Monday.as_abbr(), "monday");
+    assert_eq!(Day::Tuesday.as_abbr(), "tu");
+    assert_eq!(Day::from_code("TUESDAY"), Ok(Day::Tuesday));
+    assert_eq!(Day::from_label("Monday"), Ok(Day::Monday));
+    assert_eq!(Day::from_abbr("tu"), Ok(Day::Tuesday));
+    assert_eq!(Day::from_code("mon"), Err(MyError("mon".to_owned())));
+
+    assert_eq!(Translated::Red.as_ref(), "red");
+    assert_eq!(Translated::Red.to_string(), "red");
+    assert_eq!(Translated::Red.display_in("en"), "Red");
+    assert_eq!(Translated::Red.display_in("pt_BR"), "Vermelho");
+    assert_eq!(Translated::Green.display_in("en"), "green");
+    assert_eq!(Translated::Green.display_in("fr"), "green");
+    assert_eq!(
+        Translated::from_str_in("pt", "Verde"),
+        Ok(Translated::Green)
+    );
+    assert_eq!(
+        Translated::from_str_in("en", "green"),
+        Ok(Translated::Green)
+    );
+    assert_eq!(Translated::from_str_in("fr", "red"), Ok(Translated::Red));
+    assert!(Translated::from_str_in("en", "red").is_err());
+    assert_eq!(Translated::LOCALES, ["en", "pt", "pt_BR"]);
+
+    assert_eq!("one".parse(), Ok(Generic::<4>::One));
+    assert_eq!(Generic::<4>::Two.to_string(), "two");
+    assert_eq!(Generic::<4>::try_from(&b"two"[..]), Ok(Generic::Two));
+    assert_eq!(Generic::<4>::try_from(OsStr::new("one")), Ok(Generic::One));
+    assert_eq!(Generic::<4>::parse_prefix("one!"), Some((Generic::One, "!")));
+    assert_eq!(Generic::<4>::from_code("TWO"), Ok(Generic::Two));
+    assert_eq!(Generic::<4>::One.display_in("pt"), "um");
+
+    assert_eq!("ONE".parse(), Ok(Reexported::One));
+    assert_eq!(
+        Reexported::parse_with_info("uno"),
+        Ok((Reexported::One, MatchKind::Deprecated))
+    );
+    assert_eq!(Reexported::from_bytes(b"one"), Ok(Reexported::One));
+    assert_eq!(Reexported::from_str_in("pt", "Um"), Ok(Reexported::One));
+
+    assert_eq!("ENABLED".parse(), Ok(Cfgd::Enabled));
+    assert!("disabled".parse::<Cfgd>().is_err());
+    assert!("gone".parse::<Cfgd>().is_err());
+    assert_eq!(Cfgd::closest_match("disable"), None);
+    assert_eq!(Cfgd::Enabled.as_code(), "E");
+    assert!(Cfgd::from_code("D").is_err());
+    assert_eq!(Cfgd::from_str_in("pt", "Ligado"), Ok(Cfgd::Enabled));
+    assert_eq!(Cfgd::parse_prefix("disabled"), None);
+    assert_eq!(Cfgd::from_str_const("disabled"), None);
+
+    assert_eq!("Visible".parse(), Ok(Sentinel::Visible));
+    assert!("Internal".parse::<Sentinel>().is_err());
+    assert!("__NonExhaustive".parse::<Sentinel>().is_err());
+    assert!("<hidden>".parse::<Sentinel>().is_err());
+    assert_eq!("Hidden".parse(), Ok(Sentinel::Hidden));
+    assert_eq!("secret".parse(), Ok(Sentinel::Hidden));
+    assert_eq!(Sentinel::Internal.as_ref(), "Internal");
+    assert_eq!(Sentinel::Hidden.to_string(), "<hidden>");
+    assert_eq!(Sentinel::__NonExhaustive.as_str(), "<hidden>");
+    assert_eq!(Sentinel::Hidden.as_code(), "<hidden>");
+    assert!(Sentinel::from_code("Internal").is_err());
+    assert_eq!(Sentinel::parse_prefix("Internal"), None);
+    assert_eq!(Sentinel::from_str_const("Internal"), None);
+    assert_eq!("B".parse(), Ok(NoDisplay::B));
+
+    assert_eq!(Renamed::NAMES, ["new_name", "other"]);
+    assert_eq!(
+        Renamed::ACCEPTED,
+        [
+            ("new_name", Renamed::NewName),
+            ("newest", Renamed::NewName),
+            ("old_name", Renamed::NewName),
+            ("older_name", Renamed::NewName),
+            ("other", Renamed::Other),
+        ]
+    );
+    assert_eq!(Sentinel::NAMES, ["Visible", "Hidden"]);
+    assert_eq!(Cfgd::NAMES, ["enabled"]);
+    assert_eq!(Cfgd::ACCEPTED, [("enabled", Cfgd::Enabled)]);
+    const SCOPED: &[(&str, Scoped)] = Scoped::ACCEPTED;
+    assert_eq!(SCOPED, [("Variant", Scoped::Variant), ("both", Scoped::Variant), ("cli", Scoped::Variant)]);
+
+    assert_eq!("two-words".parse(), Ok(Lenient::TwoWords));
+    assert_eq!("TWO_WORDS".parse(), Ok(Lenient::TwoWords));
+    assert_eq!("TwoWords".parse(), Ok(Lenient::TwoWords));
+    assert_eq!("two words".parse(), Ok(Lenient::TwoWords));
+    assert_eq!("TURTLE".parse(), Ok(Lenient::Turtle));
+    assert_eq!("żÓŁW".parse(), Ok(Lenient::Turtle));
+    assert!("TUR-TLE".parse::<Lenient>().is_err());
+    assert_eq!("strict".parse(), Ok(Lenient::Strict));
+    assert!("STRICT".parse::<Lenient>().is_err());
+
+    assert_eq!(Protocol::Http2Error.as_ref(), "http_2_error");
+    assert_eq!(Protocol::HTTPRequest.as_ref(), "http_request");
+    assert_eq!(Protocol::Ipv4Addr.as_ref(), "ipv_4_addr");
+    assert_eq!(Protocol::Utf8Text.as_ref(), "UTF8_TEXT");
+    assert_eq!("ipv-4-addr".parse(), Ok(Protocol::Ipv4Addr));
+    assert_eq!(Protocol::Http2Error.as_attached(), "http2-error");
+
+    assert_eq!(Event::UserCreated.as_ref(), "ev.user_created.v1");
+    assert_eq!(Event::UserDeleted.as_ref(), "ev.user_deleted.v2");
+    assert_eq!(Event::Legacy.as_ref(), "legacy");
+    assert_eq!("ev.user-created.v1".parse(), Ok(Event::UserCreated));
+    assert_eq!("ev.user-deleted.v2".parse(), Ok(Event::UserDeleted));
+    assert!("ev.user-deleted.v1".parse::<Event>().is_err());
+    assert_eq!(Event::UserCreated.as_topic(), "events/user-created");
+
+    assert_eq!("TwoWords".parse(), Ok(Styled::TwoWords));
+    assert_eq!("two_words".parse(), Ok(Styled::TwoWords));
+    assert_eq!("twowords".parse(), Ok(Styled::TwoWords));
+    assert_eq!("two-words".parse(), Ok(Styled::TwoWords));
+    assert_eq!("TWO_WORDS".parse(), Ok(Styled::TwoWords));
+    assert_eq!("one".parse(), Ok(Styled::One));
+    assert_eq!(Styled::TwoWords.as_ref(), "TwoWords");
+    assert!("Renamed".parse::<Styled>().is_err());
+
+    assert_eq!(VARIANT_ONE, "variant❤️.❤️number❤️.❤️one");
+    assert_eq!(
+        PARSED,
+        [
+            Some(CoolEnum::VariantNumberOne),
+            Some(CoolEnum::VariantNumberTwo),
+            None
+        ]
+    );
+    assert_eq!(Named::A.name(), "A");
+    assert_eq!(Lenient::from_str_const("TWO_WORDS"), None);
+
+    assert_eq!(
+        CoolEnum::from_bytes(b"variant_number_one"),
+        Ok(CoolEnum::VariantNumberOne)
+    );
+    assert_eq!(
+        CoolEnum::try_from(&b"VariantNumberOne"[..]),
+        Ok(CoolEnum::VariantNumberOne)
+    );
+    assert_eq!(
+        CoolEnum::try_from(OsStr::new("VariantNumberOne")),
+        Ok(CoolEnum::VariantNumberOne)
+    );
+    assert_eq!(Lenient::from_bytes(b"TWO_WORDS"), Ok(Lenient::TwoWords));
+    let err = CoolEnum::from_bytes(b"variant\xffone").unwrap_err();
+    assert_eq!(err.input(), "variant\u{fffd}one");
+    assert_eq!(
+        CustomError::try_from(&b"\xff"[..]),
+        Err(MyError("\u{fffd}".to_owned()))
+    );
+
+    for (s, c) in [
+        ("BR", Country::Br),
+        ("Brasil", Country::Br),
+        ("PT", Country::Pt),
+        ("AR", Country::Ar),
+        ("AU", Country::Au),
+        ("AT", Country::At),
+        ("BE", Country::Be),
+        ("BO", Country::Bo),
+        ("Portugal", Country::Portugal),
+    ] {
+        assert_eq!(s.parse(), Ok(c));
+    }
+    for s in ["", "B", "BB", "CR", "Brazil", "PORTUGAL"] {
+        assert!(s.parse::<Country>().is_err());
+    }
+
+    assert_eq!(
+        Country::parse_prefix("Brasil!"),
+        Some((Country::Br, "!"))
+    );
+    assert_eq!(
+        Country::parse_prefix("Portugal e BR"),
+        Some((Country::Portugal, " e BR"))
+    );
+    assert_eq!(Country::parse_prefix("PTBR"), Some((Country::Pt, "BR")));
+    assert_eq!(Country::parse_prefix("pt"), None);
+
+    assert_eq!("cli".parse(), Ok(Scoped::Variant));
+    assert!("old_name".parse::<Scoped>().is_err());
+    assert_eq!("two_words".parse(), Ok(ScopedStyled::TwoWords));
 }
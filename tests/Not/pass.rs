@@ -35,8 +35,28 @@ enum MultipleVariants {
     OppositeOfC,
 }
 
+#[derive(Not, Debug, PartialEq, Eq, Clone, Copy)]
+enum Generic<const N: usize>
+where
+    [u8; N]: Default,
+{
+    A,
+    B,
+}
+
+#[derive(Not, Debug, PartialEq, Eq, Clone, Copy)]
+enum Cfgd {
+    #[not(A)]
+    A,
+    #[cfg(any())]
+    #[not(Missing)]
+    B,
+}
+
 fn main() {
     assert_eq!(!OneVariant::A, OneVariant::A);
+    assert_eq!(!Generic::<4>::A, Generic::B);
+    assert_eq!(!Cfgd::A, Cfgd::A);
     assert_eq!(!TwoVariants::A, TwoVariants::B);
     assert_eq!(!TwoVariantsWithAttrb::A, TwoVariantsWithAttrb::A);
     assert_eq!(!TwoVariantsWithAttrb::B, TwoVariantsWithAttrb::A);
@@ -0,0 +1,32 @@
+//! Derives on a crate that only has this one as `enums`
+
+#[derive(Debug, enums::FromToStr, enums::All, PartialEq, Eq)]
+#[fromtostr(crate = "::enums")]
+#[fromtostr(format(style = "snake"))]
+#[fromtostr(accept_styles("kebab"))]
+#[fromtostr(suggest)]
+#[fromtostr(repr(code(style = "UPPER")))]
+pub enum Color {
+    #[fromtostr(aliases(serde = "crimson"))]
+    #[fromtostr(locale(pt = "vermelho"))]
+    DarkRed,
+    #[fromtostr(other)]
+    Unknown,
+}
+
+#[derive(Debug, enums::FromToStr, PartialEq, Eq)]
+#[fromtostr(crate = "::enums")]
+#[fromtostr(serde(repr = "u8"))]
+pub enum Code {
+    A = 1,
+}
+
+#[test]
+fn facade() {
+    assert_eq!("dark-red".parse(), Ok(Color::DarkRed));
+    assert_eq!(Color::DarkRed.as_code(), "DARKRED");
+    assert_eq!(Color::DarkRed.display_in("pt"), "vermelho");
+    assert_eq!(Color::closest_match("dark_rad"), Some("dark_red"));
+    assert_eq!(Color::all(), [Color::DarkRed, Color::Unknown]);
+    assert_eq!("A".parse(), Ok(Code::A));
+}
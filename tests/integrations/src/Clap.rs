@@ -0,0 +1,58 @@
+use clap::ValueEnum;
+use fieldless_enum_tools::FromToStr;
+
+#[derive(Debug, FromToStr, PartialEq, Eq, Clone, Copy)]
+#[fromtostr(value_enum)]
+#[fromtostr(format(style = "kebab"))]
+enum Color {
+    /// Bright red
+    #[fromtostr(aliases("crimson"))]
+    LightRed,
+    /// Very dark blue,
+    /// almost black
+    DarkBlue,
+    #[cfg(any())]
+    Disabled,
+    #[fromtostr(skip(parse))]
+    Internal,
+}
+
+// no Clone, but it doesn't opt into `ValueEnum`
+#[derive(Debug, FromToStr)]
+enum NotClone {
+    A,
+}
+
+// skipping wins over opting in
+#[derive(Debug, FromToStr)]
+#[fromtostr(value_enum)]
+#[fromtostr(skip(ValueEnum))]
+enum Skipped {
+    A,
+}
+
+pub fn tests() {
+    assert_eq!(Color::value_variants(), [Color::LightRed, Color::DarkBlue]);
+
+    let value = Color::LightRed.to_possible_value().unwrap();
+    assert_eq!(value.get_name(), "light-red");
+    assert_eq!(value.get_help().unwrap().to_string(), "Bright red");
+    assert!(value.matches("crimson", false));
+    assert_eq!(
+        Color::DarkBlue
+            .to_possible_value()
+            .unwrap()
+            .get_help()
+            .unwrap()
+            .to_string(),
+        "Very dark blue,\nalmost black"
+    );
+    assert!(Color::Internal.to_possible_value().is_none());
+
+    assert_eq!(Color::from_str("crimson", false), Ok(Color::LightRed));
+    assert_eq!(Color::from_str("DARK-BLUE", true), Ok(Color::DarkBlue));
+    assert!(Color::from_str("Internal", true).is_err());
+
+    let _ = NotClone::A;
+    let _ = Skipped::A;
+}
@@ -0,0 +1,39 @@
+use fieldless_enum_tools::FromToStr;
+use rusqlite::{Connection, Error};
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(format(style = "snake"))]
+enum Status {
+    #[fromtostr(aliases("done"))]
+    Finished,
+    InProgress,
+}
+
+pub fn tests() {
+    let db = Connection::open_in_memory().unwrap();
+    db.execute("CREATE TABLE tasks (status TEXT NOT NULL)", [])
+        .unwrap();
+    db.execute("INSERT INTO tasks VALUES (?1)", [Status::InProgress])
+        .unwrap();
+
+    let stored: String = db
+        .query_row("SELECT status FROM tasks", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(stored, "in_progress");
+    let status: Status = db
+        .query_row("SELECT status FROM tasks", [], |r| r.get(0))
+        .unwrap();
+    assert_eq!(status, Status::InProgress);
+
+    let alias: Status = db.query_row("SELECT 'done'", [], |r| r.get(0)).unwrap();
+    assert_eq!(alias, Status::Finished);
+
+    let err = db
+        .query_row("SELECT 'unknown'", [], |r| r.get::<_, Status>(0))
+        .unwrap_err();
+    assert!(matches!(err, Error::FromSqlConversionFailure(..)));
+    let err = db
+        .query_row("SELECT 1", [], |r| r.get::<_, Status>(0))
+        .unwrap_err();
+    assert!(matches!(err, Error::InvalidColumnType(..)));
+}
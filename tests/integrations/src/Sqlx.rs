@@ -0,0 +1,78 @@
+use fieldless_enum_tools::FromToStr;
+use futures_executor::block_on;
+use sqlx::{Connection, SqliteConnection};
+
+#[derive(Debug, FromToStr, PartialEq, Eq)]
+#[fromtostr(sqlx)]
+#[fromtostr(format(style = "snake"))]
+enum Status {
+    #[fromtostr(aliases("done"))]
+    Finished,
+    InProgress,
+}
+
+// without opting in, sqlx's traits are left to implement by hand
+#[derive(Debug, FromToStr)]
+enum Manual {
+    A,
+}
+
+impl sqlx::Type<sqlx::Sqlite> for Manual {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+// skipping wins over opting in
+#[derive(Debug, FromToStr)]
+#[fromtostr(sqlx)]
+#[fromtostr(skip(Type, Encode, Decode))]
+enum Skipped {
+    A,
+}
+
+impl sqlx::Type<sqlx::Sqlite> for Skipped {
+    fn type_info() -> sqlx::sqlite::SqliteTypeInfo {
+        <i64 as sqlx::Type<sqlx::Sqlite>>::type_info()
+    }
+}
+
+pub fn tests() {
+    block_on(async {
+        let mut db = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE tasks (status TEXT NOT NULL)")
+            .execute(&mut db)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO tasks VALUES (?)")
+            .bind(Status::InProgress)
+            .execute(&mut db)
+            .await
+            .unwrap();
+
+        let stored: String = sqlx::query_scalar("SELECT status FROM tasks")
+            .fetch_one(&mut db)
+            .await
+            .unwrap();
+        assert_eq!(stored, "in_progress");
+        let status: Status = sqlx::query_scalar("SELECT status FROM tasks")
+            .fetch_one(&mut db)
+            .await
+            .unwrap();
+        assert_eq!(status, Status::InProgress);
+
+        let alias: Status = sqlx::query_scalar("SELECT 'done'")
+            .fetch_one(&mut db)
+            .await
+            .unwrap();
+        assert_eq!(alias, Status::Finished);
+
+        let err = sqlx::query_scalar::<_, Status>("SELECT 'unknown'")
+            .fetch_one(&mut db)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, sqlx::Error::ColumnDecode { .. }));
+    });
+
+    let _ = (Manual::A, Skipped::A);
+}
@@ -0,0 +1,14 @@
+//! Integrations with crates newer than the MSRV, databases using a bundled sqlite
+#![cfg(test)]
+#![allow(non_snake_case)]
+
+mod Clap;
+mod Rusqlite;
+mod Sqlx;
+
+#[test]
+fn tests() {
+    Clap::tests();
+    Rusqlite::tests();
+    Sqlx::tests();
+}